@@ -6,15 +6,288 @@
 //
 
 //! 编码器.
+//!
+//! [CodecReader]和[CodecWriter]包装[AsyncRead]和[AsyncWrite], 流式编码或解码.
+//!
+//! [Encoding]是字节与文本之间的编码的统一接口, 可以用[find_encoding]按名称选择.
+//!
+//! [VarintEncoder], [BinaryReader], [BinaryWriter]和[TlvEncoder]用于紧凑的二进制格式.
+//!
+//! [Gb18030Encoder]在GB18030, GBK与UTF-8之间转换.
 
 /* Use. */
 
+use std::io::Error as StdIoError;
+use std::io::ErrorKind as StdIoErrorKind;
+use std::pin::Pin;
+use std::sync::OnceLock;
+use std::task::Context;
+use std::task::Poll;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncWrite;
+use tokio::io::ReadBuf;
+
 /* Enum. */
 
+/// Url各个部分的编码集合, 用于[UrlEncoder::encode_component].
+///
+/// 字母, 数字和'-._~'在所有集合里都不编码, 除此之外:
+///
+/// - PathSegment, 路径的一段, 不编码"!$&'()*+,;=:@", 编码'/'.
+/// - Query, 参数的键或值, 不编码"!$'()*,;:@/?", 编码'&', '=', '+'和'#'.
+/// - Fragment, 片段, 不编码"!$&'()*+,;=:@/?".
+/// - Userinfo, 用户名或密码, 不编码"!$&'()*+,;=", 编码':'.
+/// - Form, application/x-www-form-urlencoded, 只有字母, 数字和'*-._'不编码, 空格编码成'+'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlEncodeSet {
+    PathSegment,
+    Query,
+    Fragment,
+    Userinfo,
+    Form,
+}
+
+/// 流式编码器使用的编码.
+#[derive(Debug, Clone, Copy)]
+enum Codec {
+    Base64Encode(Base64Config),
+    Base64Decode(Base64Config),
+    HexEncode,
+    HexDecode,
+}
+
 /* Trait. */
 
+/// 字节与文本之间的编码, 可以由配置的名称在运行时选择, 见[find_encoding].
+///
+/// 实现的有[Base64Encoder], [Base64Config], [Base32Encoder], [Base58Encoder], [Ascii85Encoder],
+/// [Z85Encoder], [HexEncoder], [UrlEncoder]和[QuotedPrintableEncoder].
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::Encoding;
+///
+/// let encoding: &dyn Encoding = iceyee_encoder::find_encoding("base32").unwrap();
+/// let mut output: String = String::with_capacity(encoding.encoded_length(5));
+/// encoding.encode_to("hello".as_bytes(), &mut output);
+/// assert_eq!(output, "NBSWY3DP");
+/// assert_eq!(encoding.decode(&output).unwrap(), "hello".as_bytes());
+/// ```
+pub trait Encoding: Send + Sync {
+    /// 名称, 同[find_encoding]的参数.
+    fn name(&self) -> &'static str;
+
+    /// 编码后的长度的上限, length是原始数据的字节数.
+    fn encoded_length(&self, length: usize) -> usize;
+
+    /// 解码后的长度的上限, length是文本的字节数.
+    fn decoded_length(&self, length: usize) -> usize;
+
+    /// 编码, 追加到output.
+    ///
+    /// output的剩余容量不小于[Encoding::encoded_length]时, 除了[Base58Encoder], 都不会分配内存.
+    fn encode_to(&self, input: &[u8], output: &mut String);
+
+    /// 解码, 追加到output, 失败时output可能已经追加了一部分.
+    ///
+    /// - @exception 同各个编码的decode.
+    fn decode_to(&self, input: &str, output: &mut Vec<u8>) -> Result<(), String>;
+
+    /// 编码.
+    fn encode(&self, input: &[u8]) -> String {
+        let mut output: String = String::with_capacity(self.encoded_length(input.len()));
+        self.encode_to(input, &mut output);
+        return output;
+    }
+
+    /// 解码.
+    ///
+    /// - @exception 同各个编码的decode.
+    fn decode(&self, input: &str) -> Result<Vec<u8>, String> {
+        let mut output: Vec<u8> = Vec::with_capacity(self.decoded_length(input.len()));
+        self.decode_to(input, &mut output)?;
+        return Ok(output);
+    }
+}
+
 /* Struct. */
 
+/// Base64编码的选项, 用于[Base64Encoder::encode_with]和[Base64Encoder::decode_with].
+///
+/// - url_safe, 使用RFC 4648 §5的字母表, '-'和'_'代替'+'和'/', 默认false.
+/// - padding, 编码时补'=', 解码时要求有'=', 默认true.
+/// - lenient, 解码时跳过空白字符, 并且不要求补'=', 默认false.
+/// - line_width, 编码时每隔多少个字符插入'\r\n', 0表示不换行, 默认0.
+///   不为0时, 解码也会跳过'\r'和'\n'.
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::Base64Config;
+/// use iceyee_encoder::Base64Encoder;
+///
+/// let config: Base64Config = Base64Config::new().set_url_safe(true).set_padding(false);
+/// assert_eq!(Base64Encoder::encode_with(&[0xFB, 0xFF], &config), "-_8");
+/// assert_eq!(Base64Encoder::decode_with("-_8", &config).unwrap(), vec![0xFB, 0xFF]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base64Config {
+    url_safe: bool,
+    padding: bool,
+    lenient: bool,
+    line_width: usize,
+}
+
+impl Base64Config {
+    /// 标准, 同[Base64Encoder::encode]和[Base64Encoder::decode].
+    pub const STANDARD: Base64Config = Base64Config {
+        url_safe: false,
+        padding: true,
+        lenient: false,
+        line_width: 0,
+    };
+
+    /// URL安全的字母表, 补'='.
+    pub const URL_SAFE: Base64Config = Base64Config {
+        url_safe: true,
+        padding: true,
+        lenient: false,
+        line_width: 0,
+    };
+
+    /// URL安全的字母表, 不补'=', 用于JWT和cookie.
+    pub const URL_SAFE_NO_PAD: Base64Config = Base64Config {
+        url_safe: true,
+        padding: false,
+        lenient: false,
+        line_width: 0,
+    };
+
+    /// MIME, 每76个字符换行, 用于邮件.
+    pub const MIME: Base64Config = Base64Config {
+        url_safe: false,
+        padding: true,
+        lenient: true,
+        line_width: 76,
+    };
+
+    pub fn new() -> Self {
+        return Self::STANDARD;
+    }
+
+    pub fn set_url_safe(mut self, url_safe: bool) -> Self {
+        self.url_safe = url_safe;
+        return self;
+    }
+
+    pub fn set_padding(mut self, padding: bool) -> Self {
+        self.padding = padding;
+        return self;
+    }
+
+    pub fn set_lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        return self;
+    }
+
+    pub fn set_line_width(mut self, line_width: usize) -> Self {
+        self.line_width = line_width;
+        return self;
+    }
+
+    pub fn url_safe(&self) -> bool {
+        return self.url_safe;
+    }
+
+    pub fn padding(&self) -> bool {
+        return self.padding;
+    }
+
+    pub fn lenient(&self) -> bool {
+        return self.lenient;
+    }
+
+    pub fn line_width(&self) -> usize {
+        return self.line_width;
+    }
+}
+
+impl Default for Base64Config {
+    fn default() -> Self {
+        return Self::STANDARD;
+    }
+}
+
+impl Encoding for Base64Config {
+    fn name(&self) -> &'static str {
+        return if self.url_safe && !self.padding {
+            "base64url-nopad"
+        } else if self.url_safe {
+            "base64url"
+        } else if self.line_width != 0 {
+            "base64-mime"
+        } else {
+            "base64"
+        };
+    }
+
+    fn encoded_length(&self, length: usize) -> usize {
+        let length: usize = if self.padding {
+            (length + 2) / 3 * 4
+        } else {
+            (length * 4 + 2) / 3
+        };
+        if self.line_width != 0 && 0 < length {
+            return length + (length - 1) / self.line_width * 2;
+        }
+        return length;
+    }
+
+    fn decoded_length(&self, length: usize) -> usize {
+        return length / 4 * 3 + 2;
+    }
+
+    fn encode_to(&self, input: &[u8], output: &mut String) {
+        let table: &[u8] = if self.url_safe {
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_".as_bytes()
+        } else {
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/".as_bytes()
+        };
+        let line_width: usize = self.line_width;
+        let mut column: usize = 0;
+        let mut push = |c: u8| {
+            if line_width != 0 && column == line_width {
+                output.push_str("\r\n");
+                column = 0;
+            }
+            output.push(c as char);
+            column += 1;
+        };
+        for chunk in input.chunks(3) {
+            let b0: u8 = chunk[0];
+            let b1: u8 = if 1 < chunk.len() { chunk[1] } else { 0 };
+            let b2: u8 = if 2 < chunk.len() { chunk[2] } else { 0 };
+            push(table[(b0 >> 2) as usize]);
+            push(table[(((b0 & 0b00000011) << 4) | (b1 >> 4)) as usize]);
+            if 1 < chunk.len() {
+                push(table[(((b1 & 0b00001111) << 2) | (b2 >> 6)) as usize]);
+            } else if self.padding {
+                push(b'=');
+            }
+            if 2 < chunk.len() {
+                push(table[(b2 & 0b00111111) as usize]);
+            } else if self.padding {
+                push(b'=');
+            }
+        }
+        return;
+    }
+
+    fn decode_to(&self, input: &str, output: &mut Vec<u8>) -> Result<(), String> {
+        output.extend_from_slice(&Base64Encoder::decode_with(input, self)?);
+        return Ok(());
+    }
+}
+
 /// Base64编码.
 #[derive(Debug, Clone)]
 pub struct Base64Encoder;
@@ -184,660 +457,3542 @@ impl Base64Encoder {
         }
         return Ok(output);
     }
+
+    /// 编码, 可以选择字母表, 是否补'=', 以及换行.
+    pub fn encode_with(input: &[u8], config: &Base64Config) -> String {
+        return Encoding::encode(config, input);
+    }
+
+    /// 解码, 可以选择字母表, 是否要求'=', 以及是否跳过空白字符.
+    ///
+    /// - @exception 无效的长度.
+    /// - @exception 出现未预期的字符.
+    pub fn decode_with(input: &str, config: &Base64Config) -> Result<Vec<u8>, String> {
+        let mut values: Vec<u8> = Vec::with_capacity(input.len());
+        let mut padding: usize = 0;
+        for c in input.bytes() {
+            let value: u8 = match c {
+                b'A'..=b'Z' => c - b'A',
+                b'a'..=b'z' => c - b'a' + 26,
+                b'0'..=b'9' => c - b'0' + 52,
+                b'+' if !config.url_safe => 62,
+                b'/' if !config.url_safe => 63,
+                b'-' if config.url_safe => 62,
+                b'_' if config.url_safe => 63,
+                b'=' if padding < 2 => {
+                    padding += 1;
+                    continue;
+                }
+                b' ' | b'\t' | b'\r' | b'\n' if config.lenient => continue,
+                b'\r' | b'\n' if config.line_width != 0 => continue,
+                _ => return Err(iceyee_error::c!("出现未预期的字符")),
+            };
+            if padding != 0 {
+                /* '='只能在最后. */
+                return Err(iceyee_error::c!("出现未预期的字符"));
+            }
+            values.push(value);
+        }
+        if padding != 0 && !config.padding && !config.lenient {
+            return Err(iceyee_error::c!("出现未预期的字符"));
+        }
+        let length: usize = values.len();
+        if length % 4 == 1 {
+            return Err(iceyee_error::c!("无效的长度"));
+        }
+        if padding != 0 && (length + padding) % 4 != 0 {
+            return Err(iceyee_error::c!("无效的长度"));
+        }
+        if padding == 0 && length % 4 != 0 && config.padding && !config.lenient {
+            return Err(iceyee_error::c!("无效的长度"));
+        }
+        let mut output: Vec<u8> = Vec::with_capacity(length / 4 * 3 + 2);
+        for chunk in values.chunks(4) {
+            output.push((chunk[0] << 2) | (chunk[1] >> 4));
+            if 2 < chunk.len() {
+                output.push((chunk[1] << 4) | (chunk[2] >> 2));
+            }
+            if 3 < chunk.len() {
+                output.push((chunk[2] << 6) | chunk[3]);
+            }
+        }
+        return Ok(output);
+    }
+
+    /// 流式编码, 从reader读取原始数据, 读出编码后的文本.
+    pub fn encode_reader<R>(reader: R, config: &Base64Config) -> CodecReader<R>
+    where
+        R: AsyncRead + Unpin,
+    {
+        return CodecReader::new(reader, Codec::Base64Encode(*config));
+    }
+
+    /// 流式解码, 从reader读取编码后的文本, 读出原始数据.
+    pub fn decode_reader<R>(reader: R, config: &Base64Config) -> CodecReader<R>
+    where
+        R: AsyncRead + Unpin,
+    {
+        return CodecReader::new(reader, Codec::Base64Decode(*config));
+    }
+
+    /// 流式编码, 写入原始数据, 编码后的文本写到writer.
+    pub fn encode_writer<W>(writer: W, config: &Base64Config) -> CodecWriter<W>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        return CodecWriter::new(writer, Codec::Base64Encode(*config));
+    }
+
+    /// 流式解码, 写入编码后的文本, 原始数据写到writer.
+    pub fn decode_writer<W>(writer: W, config: &Base64Config) -> CodecWriter<W>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        return CodecWriter::new(writer, Codec::Base64Decode(*config));
+    }
 }
 
-/// 十六进制编码.
+impl Encoding for Base64Encoder {
+    fn name(&self) -> &'static str {
+        return "base64";
+    }
+
+    fn encoded_length(&self, length: usize) -> usize {
+        return Base64Config::STANDARD.encoded_length(length);
+    }
+
+    fn decoded_length(&self, length: usize) -> usize {
+        return Base64Config::STANDARD.decoded_length(length);
+    }
+
+    fn encode_to(&self, input: &[u8], output: &mut String) {
+        Base64Config::STANDARD.encode_to(input, output);
+        return;
+    }
+
+    fn decode_to(&self, input: &str, output: &mut Vec<u8>) -> Result<(), String> {
+        output.extend_from_slice(&Base64Encoder::decode(input)?);
+        return Ok(());
+    }
+}
+
+/// Base32编码, RFC 4648, 用于TOTP的密钥.
 #[derive(Debug, Clone)]
-pub struct HexEncoder;
+pub struct Base32Encoder;
 
-impl HexEncoder {
+impl Base32Encoder {
     /// 编码.
     pub fn encode(input: &[u8]) -> String {
-        static TABLE: [char; 16] = [
-            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
-        ];
-        let mut output: String = String::new();
-        for x in input {
-            let high: u8 = (x >> 4) & 0x0F;
-            let low: u8 = (x >> 0) & 0x0F;
-            output.push(TABLE[high as usize]);
-            output.push(TABLE[low as usize]);
-        }
-        return output;
+        return Encoding::encode(&Base32Encoder, input);
     }
 
-    /// 解码.
+    /// 解码, 不区分大小写, 可以省略'='.
     ///
     /// - @exception 无效的长度.
     /// - @exception 出现未预期的字符.
     pub fn decode(input: &str) -> Result<Vec<u8>, String> {
-        let input: String = input.to_string();
-        let length: usize = input.len();
-        if length % 2 != 0 {
+        let input: &str = input.trim_end_matches('=');
+        if [1, 3, 6].contains(&(input.len() % 8)) {
             return Err(iceyee_error::c!("无效的长度"));
         }
-        let mut output: Vec<u8> = Vec::new();
-        let input: &[u8] = input.as_bytes();
-        let mut high: u8;
-        let mut low: u8;
-        for x in 0..(length / 2) {
-            match input[x * 2] {
-                b'0'..=b'9' => {
-                    high = input[x * 2] - b'0';
-                }
-                b'a'..=b'f' => {
-                    high = input[x * 2] - b'a' + 10;
-                }
-                b'A'..=b'F' => {
-                    high = input[x * 2] - b'A' + 10;
-                }
-                _ => return Err(iceyee_error::c!("出现未预期的字符")),
-            }
-            match input[x * 2 + 1] {
-                b'0'..=b'9' => {
-                    low = input[x * 2 + 1] - b'0';
-                }
-                b'a'..=b'f' => {
-                    low = input[x * 2 + 1] - b'a' + 10;
-                }
-                b'A'..=b'F' => {
-                    low = input[x * 2 + 1] - b'A' + 10;
-                }
+        let mut output: Vec<u8> = Vec::with_capacity(input.len() * 5 / 8);
+        let mut value: u64 = 0;
+        let mut bits: usize = 0;
+        for x in input.bytes() {
+            let y: u8 = match x {
+                b'A'..=b'Z' => x - b'A',
+                b'a'..=b'z' => x - b'a',
+                b'2'..=b'7' => x - b'2' + 26,
                 _ => return Err(iceyee_error::c!("出现未预期的字符")),
+            };
+            value = (value << 5) | y as u64;
+            bits += 5;
+            if 8 <= bits {
+                bits -= 8;
+                output.push((value >> bits) as u8);
             }
-            let b: u8 = (high << 4) | (low << 0);
-            output.push(b);
         }
         return Ok(output);
     }
+}
 
-    /// 编码64位整数.
-    pub fn encode_number(mut input: u64) -> String {
-        static TABLE: [char; 16] = [
-            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
-        ];
-        if input == 0 {
-            return "0".to_string();
-        }
-        let mut output: Vec<u8> = Vec::new();
-        while input != 0 {
-            let digit: usize = (input & 0xF) as usize;
-            output.push(TABLE[digit] as u8);
-            input = input >> 4;
-            let digit: usize = (input & 0xF) as usize;
-            output.push(TABLE[digit] as u8);
-            input = input >> 4;
-        }
-        output.reverse();
-        return String::from_utf8(output).unwrap();
+impl Encoding for Base32Encoder {
+    fn name(&self) -> &'static str {
+        return "base32";
     }
 
-    /// 解码64位整数.
-    ///
-    /// - @exception 出现未预期的字符.
-    pub fn decode_number(input: &str) -> Result<u64, String> {
-        let mut input: String = input.to_string().replace(" ", "").replace("_", "");
-        input.truncate(16);
-        let mut output: u64 = 0;
-        for x in input.as_bytes().iter() {
-            match x {
-                b'0'..=b'9' => {
-                    output <<= 4;
-                    output |= (x - b'0') as u64;
-                }
-                b'a'..=b'f' => {
-                    output <<= 4;
-                    output |= (x - b'a' + 10) as u64;
-                }
-                b'A'..=b'F' => {
-                    output <<= 4;
-                    output |= (x - b'A' + 10) as u64;
-                }
-                _ => {
-                    return Err(iceyee_error::c!("出现未预期的字符"));
+    fn encoded_length(&self, length: usize) -> usize {
+        return (length + 4) / 5 * 8;
+    }
+
+    fn decoded_length(&self, length: usize) -> usize {
+        return length * 5 / 8;
+    }
+
+    fn encode_to(&self, input: &[u8], output: &mut String) {
+        const TABLE: &[u8] = "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567".as_bytes();
+        for chunk in input.chunks(5) {
+            let mut buffer: [u8; 5] = [0; 5];
+            buffer[..chunk.len()].copy_from_slice(chunk);
+            let mut value: u64 = 0;
+            for x in buffer {
+                value = (value << 8) | x as u64;
+            }
+            /* 1, 2, 3, 4, 5字节分别对应2, 4, 5, 7, 8个字符. */
+            let length: usize = (chunk.len() * 8 + 4) / 5;
+            for x in 0..8 {
+                if x < length {
+                    let y: u64 = (value >> (35 - x * 5)) & 0b11111;
+                    output.push(TABLE[y as usize] as char);
+                } else {
+                    output.push('=');
                 }
             }
         }
-        return Ok(output);
+        return;
+    }
+
+    fn decode_to(&self, input: &str, output: &mut Vec<u8>) -> Result<(), String> {
+        output.extend_from_slice(&Base32Encoder::decode(input)?);
+        return Ok(());
     }
 }
 
-/// 进制编码.
-#[derive(Clone, Debug)]
-pub struct RadixEncoder;
+/// Base58编码, 比特币的字母表.
+#[derive(Debug, Clone)]
+pub struct Base58Encoder;
 
-impl RadixEncoder {
-    /// 字符串转64位整数.
+impl Base58Encoder {
+    const TABLE: &[u8] = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz".as_bytes();
+
+    /// 编码.
+    pub fn encode(input: &[u8]) -> String {
+        return Encoding::encode(&Base58Encoder, input);
+    }
+
+    /// 解码.
     ///
     /// - @exception 出现未预期的字符.
-    pub fn string_to_u64(input: &str) -> Result<u64, String> {
-        enum Radix {
-            Binary,
-            Octal,
-            Hexadecimal,
-        }
-        let mut input: String = input.to_string().replace(" ", "").replace("_", "");
-        let radix: Radix = if input.starts_with("0b") {
-            input = input[2..].to_string();
-            input.truncate(64);
-            Radix::Binary
-        } else if input.starts_with("0o") {
-            input = input[2..].to_string();
-            input.truncate(22);
-            Radix::Octal
-        } else if input.starts_with("0x") {
-            input = input[2..].to_string();
-            input.truncate(16);
-            Radix::Hexadecimal
-        } else {
-            Radix::Hexadecimal
-        };
-        let mut output: u64 = 0;
-        match radix {
-            Radix::Binary => {
-                for x in input.as_bytes().iter() {
-                    match x {
-                        b'0'..=b'1' => {
-                            output <<= 1;
-                            output |= (x - b'0') as u64;
-                        }
-                        _ => {
-                            return Err(iceyee_error::c!("出现未预期的字符"));
-                        }
-                    }
-                }
-            }
-            Radix::Octal => {
-                for x in input.as_bytes().iter() {
-                    match x {
-                        b'0'..=b'7' => {
-                            output <<= 3;
-                            output |= (x - b'0') as u64;
-                        }
-                        _ => {
-                            return Err(iceyee_error::c!("出现未预期的字符"));
-                        }
-                    }
-                }
+    pub fn decode(input: &str) -> Result<Vec<u8>, String> {
+        let zeros: usize = input.bytes().take_while(|x| *x == b'1').count();
+        /* 256进制的数字, 低位在前. */
+        let mut bytes: Vec<u8> = Vec::with_capacity(input.len() * 733 / 1000 + 1);
+        for x in input[zeros..].bytes() {
+            let mut carry: u32 = match Self::TABLE.iter().position(|y| *y == x) {
+                Some(y) => y as u32,
+                None => return Err(iceyee_error::c!("出现未预期的字符")),
+            };
+            for byte in bytes.iter_mut() {
+                carry += *byte as u32 * 58;
+                *byte = carry as u8;
+                carry >>= 8;
             }
-            Radix::Hexadecimal => {
-                for x in input.as_bytes().iter() {
-                    match x {
-                        b'0'..=b'9' => {
-                            output <<= 4;
-                            output |= (x - b'0') as u64;
-                        }
-                        b'a'..=b'f' => {
-                            output <<= 4;
-                            output |= (x - b'a' + 10) as u64;
-                        }
-                        b'A'..=b'F' => {
-                            output <<= 4;
-                            output |= (x - b'A' + 10) as u64;
-                        }
-                        _ => {
-                            return Err(iceyee_error::c!("出现未预期的字符"));
-                        }
-                    }
-                }
+            while carry != 0 {
+                bytes.push(carry as u8);
+                carry >>= 8;
             }
         }
+        let mut output: Vec<u8> = vec![0; zeros];
+        output.extend(bytes.iter().rev());
         return Ok(output);
     }
+}
 
-    pub fn u64_to_bin(input: u64) -> String {
-        let mut input: u64 = input;
-        let mut output: String = String::new();
-        for _x in 0..64 {
-            let y: u8 = input as u8 & 0b1;
-            input >>= 1;
-            output.push((y + b'0') as char);
-        }
-        return output.chars().rev().collect();
+impl Encoding for Base58Encoder {
+    fn name(&self) -> &'static str {
+        return "base58";
+    }
+
+    fn encoded_length(&self, length: usize) -> usize {
+        return length * 138 / 100 + 1;
+    }
+
+    fn decoded_length(&self, length: usize) -> usize {
+        return length * 733 / 1000 + 1;
+    }
+
+    fn encode_to(&self, input: &[u8], output: &mut String) {
+        /* 开头的每个0编码成'1'. */
+        let zeros: usize = input.iter().take_while(|x| **x == 0).count();
+        /* 58进制的数字, 低位在前. */
+        let mut digits: Vec<u8> = Vec::with_capacity(input.len() * 138 / 100 + 1);
+        for x in &input[zeros..] {
+            let mut carry: u32 = *x as u32;
+            for digit in digits.iter_mut() {
+                carry += (*digit as u32) << 8;
+                *digit = (carry % 58) as u8;
+                carry /= 58;
+            }
+            while carry != 0 {
+                digits.push((carry % 58) as u8);
+                carry /= 58;
+            }
+        }
+        output.reserve(zeros + digits.len());
+        for _ in 0..zeros {
+            output.push('1');
+        }
+        for digit in digits.iter().rev() {
+            output.push(Base58Encoder::TABLE[*digit as usize] as char);
+        }
+        return;
+    }
+
+    fn decode_to(&self, input: &str, output: &mut Vec<u8>) -> Result<(), String> {
+        output.extend_from_slice(&Base58Encoder::decode(input)?);
+        return Ok(());
+    }
+}
+
+/// Ascii85编码, 全0的一组编码成'z', 不包含'<~'和'~>'.
+///
+/// 长度不是4的倍数时, 最后n个字节编码成n+1个字符.
+#[derive(Debug, Clone)]
+pub struct Ascii85Encoder;
+
+impl Ascii85Encoder {
+    const TABLE: &[u8; 85] =
+        b"!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstu";
+
+    /// 编码.
+    pub fn encode(input: &[u8]) -> String {
+        return Encoding::encode(&Ascii85Encoder, input);
+    }
+
+    /// 解码, 跳过空白字符, 以及开头的'<~'和结尾的'~>'.
+    ///
+    /// - @exception 无效的长度.
+    /// - @exception 出现未预期的字符.
+    /// - @exception 数值溢出.
+    pub fn decode(input: &str) -> Result<Vec<u8>, String> {
+        let input: &str = input.trim();
+        let input: &str = input.strip_prefix("<~").unwrap_or(input);
+        let input: &str = input.strip_suffix("~>").unwrap_or(input);
+        let mut values: Vec<u8> = Vec::with_capacity(input.len());
+        let mut output: Vec<u8> = Vec::with_capacity(input.len() * 4 / 5 + 4);
+        for x in input.bytes() {
+            match x {
+                b'!'..=b'u' => values.push(x - b'!'),
+                b'z' if values.len() % 5 == 0 => {
+                    /* 'z'代表一组4个0. */
+                    base85_decode(&values, &mut output)?;
+                    values.clear();
+                    output.extend_from_slice(&[0, 0, 0, 0]);
+                }
+                b' ' | b'\t' | b'\r' | b'\n' => {}
+                _ => return Err(iceyee_error::c!("出现未预期的字符")),
+            }
+        }
+        base85_decode(&values, &mut output)?;
+        return Ok(output);
+    }
+}
+
+impl Encoding for Ascii85Encoder {
+    fn name(&self) -> &'static str {
+        return "ascii85";
+    }
+
+    fn encoded_length(&self, length: usize) -> usize {
+        return (length + 3) / 4 * 5;
+    }
+
+    fn decoded_length(&self, length: usize) -> usize {
+        /* 'z'解码成4个字节. */
+        return length * 4;
+    }
+
+    fn encode_to(&self, input: &[u8], output: &mut String) {
+        base85_encode(input, Ascii85Encoder::TABLE, true, output);
+        return;
+    }
+
+    fn decode_to(&self, input: &str, output: &mut Vec<u8>) -> Result<(), String> {
+        output.extend_from_slice(&Ascii85Encoder::decode(input)?);
+        return Ok(());
+    }
+}
+
+/// Z85编码, ZeroMQ的字母表, 适合放在源代码和json里.
+///
+/// 标准要求长度是4的倍数, 这里和[Ascii85Encoder]一样, 最后n个字节编码成n+1个字符.
+#[derive(Debug, Clone)]
+pub struct Z85Encoder;
+
+impl Z85Encoder {
+    const TABLE: &[u8; 85] =
+        b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+    /// 编码.
+    pub fn encode(input: &[u8]) -> String {
+        return Encoding::encode(&Z85Encoder, input);
+    }
+
+    /// 解码.
+    ///
+    /// - @exception 无效的长度.
+    /// - @exception 出现未预期的字符.
+    /// - @exception 数值溢出.
+    pub fn decode(input: &str) -> Result<Vec<u8>, String> {
+        let mut values: Vec<u8> = Vec::with_capacity(input.len());
+        for x in input.bytes() {
+            match Self::TABLE.iter().position(|y| *y == x) {
+                Some(y) => values.push(y as u8),
+                None => return Err(iceyee_error::c!("出现未预期的字符")),
+            }
+        }
+        let mut output: Vec<u8> = Vec::with_capacity(input.len() * 4 / 5 + 4);
+        base85_decode(&values, &mut output)?;
+        return Ok(output);
+    }
+}
+
+impl Encoding for Z85Encoder {
+    fn name(&self) -> &'static str {
+        return "z85";
+    }
+
+    fn encoded_length(&self, length: usize) -> usize {
+        return (length + 3) / 4 * 5;
+    }
+
+    fn decoded_length(&self, length: usize) -> usize {
+        return (length + 4) / 5 * 4;
+    }
+
+    fn encode_to(&self, input: &[u8], output: &mut String) {
+        base85_encode(input, Z85Encoder::TABLE, false, output);
+        return;
+    }
+
+    fn decode_to(&self, input: &str, output: &mut Vec<u8>) -> Result<(), String> {
+        output.extend_from_slice(&Z85Encoder::decode(input)?);
+        return Ok(());
+    }
+}
+
+/// [HexEncoder::hexdump]的选项.
+///
+/// - width, 每行多少字节, 每8个字节之间多一个空格, 默认16.
+/// - uppercase, 偏移量和十六进制是否用大写, 默认false, 同`hexdump -C`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexdumpConfig {
+    width: usize,
+    uppercase: bool,
+}
+
+impl HexdumpConfig {
+    pub fn new() -> Self {
+        return HexdumpConfig {
+            width: 16,
+            uppercase: false,
+        };
+    }
+
+    /// 0当作1.
+    pub fn set_width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        return self;
+    }
+
+    pub fn set_uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        return self;
+    }
+}
+
+impl Default for HexdumpConfig {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// 十六进制编码.
+#[derive(Debug, Clone)]
+pub struct HexEncoder;
+
+impl HexEncoder {
+    /// 编码.
+    pub fn encode(input: &[u8]) -> String {
+        return Encoding::encode(&HexEncoder, input);
+    }
+
+    /// 解码.
+    ///
+    /// - @exception 无效的长度.
+    /// - @exception 出现未预期的字符.
+    pub fn decode(input: &str) -> Result<Vec<u8>, String> {
+        let input: String = input.to_string();
+        let length: usize = input.len();
+        if length % 2 != 0 {
+            return Err(iceyee_error::c!("无效的长度"));
+        }
+        let mut output: Vec<u8> = Vec::new();
+        let input: &[u8] = input.as_bytes();
+        let mut high: u8;
+        let mut low: u8;
+        for x in 0..(length / 2) {
+            match input[x * 2] {
+                b'0'..=b'9' => {
+                    high = input[x * 2] - b'0';
+                }
+                b'a'..=b'f' => {
+                    high = input[x * 2] - b'a' + 10;
+                }
+                b'A'..=b'F' => {
+                    high = input[x * 2] - b'A' + 10;
+                }
+                _ => return Err(iceyee_error::c!("出现未预期的字符")),
+            }
+            match input[x * 2 + 1] {
+                b'0'..=b'9' => {
+                    low = input[x * 2 + 1] - b'0';
+                }
+                b'a'..=b'f' => {
+                    low = input[x * 2 + 1] - b'a' + 10;
+                }
+                b'A'..=b'F' => {
+                    low = input[x * 2 + 1] - b'A' + 10;
+                }
+                _ => return Err(iceyee_error::c!("出现未预期的字符")),
+            }
+            let b: u8 = (high << 4) | (low << 0);
+            output.push(b);
+        }
+        return Ok(output);
+    }
+
+    /// 编码64位整数.
+    pub fn encode_number(mut input: u64) -> String {
+        static TABLE: [char; 16] = [
+            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
+        ];
+        if input == 0 {
+            return "0".to_string();
+        }
+        let mut output: Vec<u8> = Vec::new();
+        while input != 0 {
+            let digit: usize = (input & 0xF) as usize;
+            output.push(TABLE[digit] as u8);
+            input = input >> 4;
+            let digit: usize = (input & 0xF) as usize;
+            output.push(TABLE[digit] as u8);
+            input = input >> 4;
+        }
+        output.reverse();
+        return String::from_utf8(output).unwrap();
+    }
+
+    /// 解码64位整数.
+    ///
+    /// - @exception 出现未预期的字符.
+    pub fn decode_number(input: &str) -> Result<u64, String> {
+        let mut input: String = input.to_string().replace(" ", "").replace("_", "");
+        input.truncate(16);
+        let mut output: u64 = 0;
+        for x in input.as_bytes().iter() {
+            match x {
+                b'0'..=b'9' => {
+                    output <<= 4;
+                    output |= (x - b'0') as u64;
+                }
+                b'a'..=b'f' => {
+                    output <<= 4;
+                    output |= (x - b'a' + 10) as u64;
+                }
+                b'A'..=b'F' => {
+                    output <<= 4;
+                    output |= (x - b'A' + 10) as u64;
+                }
+                _ => {
+                    return Err(iceyee_error::c!("出现未预期的字符"));
+                }
+            }
+        }
+        return Ok(output);
+    }
+
+    /// 流式编码, 从reader读取原始数据, 读出编码后的文本.
+    pub fn encode_reader<R>(reader: R) -> CodecReader<R>
+    where
+        R: AsyncRead + Unpin,
+    {
+        return CodecReader::new(reader, Codec::HexEncode);
+    }
+
+    /// 流式解码, 从reader读取编码后的文本, 读出原始数据.
+    pub fn decode_reader<R>(reader: R) -> CodecReader<R>
+    where
+        R: AsyncRead + Unpin,
+    {
+        return CodecReader::new(reader, Codec::HexDecode);
+    }
+
+    /// 流式编码, 写入原始数据, 编码后的文本写到writer.
+    pub fn encode_writer<W>(writer: W) -> CodecWriter<W>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        return CodecWriter::new(writer, Codec::HexEncode);
+    }
+
+    /// 流式解码, 写入编码后的文本, 原始数据写到writer.
+    pub fn decode_writer<W>(writer: W) -> CodecWriter<W>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        return CodecWriter::new(writer, Codec::HexDecode);
+    }
+
+    /// 格式同`hexdump -C`, 偏移量, 十六进制, 以及'|'包围的ASCII, 最后一行是总长度.
+    ///
+    /// 与`hexdump -C`不同, 重复的行不会省略成'*'.
+    ///
+    /// # Example
+    /// ```
+    /// use iceyee_encoder::HexEncoder;
+    /// use iceyee_encoder::HexdumpConfig;
+    ///
+    /// let output: String = HexEncoder::hexdump("hello world.\n".as_bytes(), &HexdumpConfig::new());
+    /// assert_eq!(
+    ///     output,
+    ///     "00000000  68 65 6c 6c 6f 20 77 6f  72 6c 64 2e 0a           |hello world..|\n0000000d\n"
+    /// );
+    /// ```
+    pub fn hexdump(input: &[u8], config: &HexdumpConfig) -> String {
+        let mut output: String = String::new();
+        if input.len() == 0 {
+            return output;
+        }
+        let hex = |x: usize, width: usize| -> String {
+            if config.uppercase {
+                format!("{:0width$X}", x, width = width)
+            } else {
+                format!("{:0width$x}", x, width = width)
+            }
+        };
+        for (x, line) in input.chunks(config.width).enumerate() {
+            output.push_str(&hex(x * config.width, 8));
+            output.push_str("  ");
+            for y in 0..config.width {
+                match line.get(y) {
+                    Some(byte) => {
+                        output.push_str(&hex(*byte as usize, 2));
+                        output.push(' ');
+                    }
+                    None => output.push_str("   "),
+                }
+                if y % 8 == 7 || y == config.width - 1 {
+                    output.push(' ');
+                }
+            }
+            output.push('|');
+            for byte in line {
+                if 0x20 <= *byte && *byte <= 0x7E {
+                    output.push(*byte as char);
+                } else {
+                    output.push('.');
+                }
+            }
+            output.push_str("|\n");
+        }
+        output.push_str(&hex(input.len(), 8));
+        output.push('\n');
+        return output;
+    }
+
+    /// 解析`hexdump -C`或者`xxd`的输出, 得到原来的数据.
+    ///
+    /// 支持`hexdump -C`省略重复行的'*', 此时需要后面一行的偏移量, 省略的必须是整数个重复行.
+    ///
+    /// 为了防止不可信的输入申请过多的内存, 结果最多1GiB.
+    ///
+    /// - @exception 错误的格式.
+    /// - @exception 偏移量不连续.
+    /// - @exception 数据过大.
+    /// - @exception 无效的长度.
+    /// - @exception 出现未预期的字符.
+    pub fn parse_hexdump(input: &str) -> Result<Vec<u8>, String> {
+        const LIMIT: usize = 1 << 30;
+        let mut output: Vec<u8> = Vec::new();
+        let mut previous: Vec<u8> = Vec::new();
+        let mut repeat: bool = false;
+        for line in input.lines() {
+            let line: &str = line.trim_end();
+            if line.trim().len() == 0 {
+                continue;
+            }
+            if line.trim() == "*" {
+                repeat = true;
+                continue;
+            }
+            /* 偏移量, 后面是':'(xxd)或者空白(hexdump -C). */
+            let length: usize = line
+                .find(|c: char| !c.is_ascii_hexdigit())
+                .unwrap_or(line.len());
+            let offset: usize = usize::from_str_radix(&line[..length], 16)
+                .map_err(|_| iceyee_error::c!("错误的格式", line))?;
+            let rest: &str = &line[length..];
+            /* xxd是两个空格之前, hexdump -C是'|'之前. */
+            let area: &str = if let Some(rest) = rest.strip_prefix(": ") {
+                match rest.find("  ") {
+                    Some(x) => &rest[..x],
+                    None => rest,
+                }
+            } else {
+                match rest.find('|') {
+                    Some(x) => &rest[..x],
+                    None => rest,
+                }
+            };
+            if repeat {
+                if offset < output.len()
+                    || previous.len() == 0
+                    || (offset - output.len()) % previous.len() != 0
+                {
+                    return Err(iceyee_error::c!("偏移量不连续", line));
+                }
+                if LIMIT < offset {
+                    return Err(iceyee_error::c!("数据过大", line));
+                }
+                while output.len() < offset {
+                    output.extend_from_slice(&previous);
+                }
+                repeat = false;
+            }
+            if offset != output.len() {
+                return Err(iceyee_error::c!("偏移量不连续", line));
+            }
+            let area: String = area.split_whitespace().collect();
+            let data: Vec<u8> = Self::decode(&area)?;
+            output.extend_from_slice(&data);
+            if data.len() != 0 {
+                previous = data;
+            }
+        }
+        if repeat {
+            return Err(iceyee_error::c!("错误的格式", "*"));
+        }
+        return Ok(output);
+    }
+}
+
+impl Encoding for HexEncoder {
+    fn name(&self) -> &'static str {
+        return "hex";
+    }
+
+    fn encoded_length(&self, length: usize) -> usize {
+        return length * 2;
+    }
+
+    fn decoded_length(&self, length: usize) -> usize {
+        return length / 2;
+    }
+
+    fn encode_to(&self, input: &[u8], output: &mut String) {
+        static TABLE: [char; 16] = [
+            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
+        ];
+        for x in input {
+            let high: u8 = (x >> 4) & 0x0F;
+            let low: u8 = (x >> 0) & 0x0F;
+            output.push(TABLE[high as usize]);
+            output.push(TABLE[low as usize]);
+        }
+        return;
+    }
+
+    fn decode_to(&self, input: &str, output: &mut Vec<u8>) -> Result<(), String> {
+        output.extend_from_slice(&HexEncoder::decode(input)?);
+        return Ok(());
+    }
+}
+
+/// 进制编码的选项, 用于[RadixEncoder::encode_unsigned]等.
+///
+/// - 字母表, 由[RadixConfig::new]指定2到36进制的标准字母表'0-9A-Z',
+///   或者由[RadixConfig::with_alphabet]指定任意字母表, 比如[RadixConfig::base62].
+/// - group, 每隔多少位插入分隔符, 0表示不分组, 默认0.
+/// - separator, 分隔符, 默认'_'.
+/// - width, 最少多少位, 不足的补0, 默认1.
+/// - prefix, 是否加'0b', '0o', '0x'前缀, 只对标准字母表的2, 8, 16进制有效, 默认false.
+/// - lowercase, 标准字母表是否用小写, 默认false.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RadixConfig {
+    alphabet: Vec<u8>,
+    custom: bool,
+    group: usize,
+    separator: char,
+    width: usize,
+    prefix: bool,
+}
+
+impl RadixConfig {
+    /// 标准字母表.
+    ///
+    /// - @exception 无效的进制, 不是2到36.
+    pub fn new(radix: u32) -> Result<Self, String> {
+        if radix < 2 || 36 < radix {
+            return Err(iceyee_error::c!("无效的进制", radix));
+        }
+        let alphabet: Vec<u8> =
+            "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ".as_bytes()[..radix as usize].to_vec();
+        return Ok(RadixConfig {
+            alphabet: alphabet,
+            custom: false,
+            group: 0,
+            separator: '_',
+            width: 1,
+            prefix: false,
+        });
+    }
+
+    /// 自定义字母表, 进制等于字母表的长度.
+    ///
+    /// - @exception 无效的字母表, 少于2个字符, 包含非ASCII字符或者重复的字符.
+    pub fn with_alphabet(alphabet: &str) -> Result<Self, String> {
+        let alphabet: Vec<u8> = alphabet.as_bytes().to_vec();
+        if alphabet.len() < 2
+            || !alphabet.is_ascii()
+            || (1..alphabet.len()).any(|x| alphabet[..x].contains(&alphabet[x]))
+        {
+            return Err(iceyee_error::c!("无效的字母表"));
+        }
+        return Ok(RadixConfig {
+            alphabet: alphabet,
+            custom: true,
+            group: 0,
+            separator: '_',
+            width: 1,
+            prefix: false,
+        });
+    }
+
+    /// 62进制, '0-9A-Za-z', 用于短ID.
+    pub fn base62() -> Self {
+        return Self::with_alphabet(
+            "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+        )
+        .expect("NEVER");
+    }
+
+    pub fn set_group(mut self, group: usize) -> Self {
+        self.group = group;
+        return self;
+    }
+
+    pub fn set_separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        return self;
+    }
+
+    pub fn set_width(mut self, width: usize) -> Self {
+        self.width = width;
+        return self;
+    }
+
+    pub fn set_prefix(mut self, prefix: bool) -> Self {
+        self.prefix = prefix;
+        return self;
+    }
+
+    pub fn set_lowercase(mut self, lowercase: bool) -> Self {
+        if !self.custom {
+            if lowercase {
+                self.alphabet.make_ascii_lowercase();
+            } else {
+                self.alphabet.make_ascii_uppercase();
+            }
+        }
+        return self;
+    }
+
+    pub fn radix(&self) -> u32 {
+        return self.alphabet.len() as u32;
+    }
+}
+
+/// 进制编码.
+#[derive(Clone, Debug)]
+pub struct RadixEncoder;
+
+impl RadixEncoder {
+    /// 字符串转64位整数, 支持'0b', '0o', '0x'前缀, 没有前缀时是十六进制, 忽略' '和'_'.
+    ///
+    /// - @exception 出现未预期的字符.
+    /// - @exception 数值溢出.
+    pub fn string_to_u64(input: &str) -> Result<u64, String> {
+        let input: String = input.to_string().replace(" ", "").replace("_", "");
+        let (radix, digits): (u32, &str) = if let Some(digits) = input.strip_prefix("0b") {
+            (2, digits)
+        } else if let Some(digits) = input.strip_prefix("0o") {
+            (8, digits)
+        } else if let Some(digits) = input.strip_prefix("0x") {
+            (16, digits)
+        } else {
+            (16, input.as_str())
+        };
+        if digits.len() == 0 {
+            return Ok(0);
+        }
+        return Self::decode_unsigned::<u64>(digits, &RadixConfig::new(radix).expect("NEVER"));
+    }
+
+    /// 编码无符号整数, 其它无符号整数可以用`.into()`转成[u128].
+    ///
+    /// # Example
+    /// ```
+    /// use iceyee_encoder::RadixConfig;
+    /// use iceyee_encoder::RadixEncoder;
+    ///
+    /// let config: RadixConfig = RadixConfig::new(2).unwrap().set_group(4).set_prefix(true);
+    /// assert_eq!(RadixEncoder::encode_unsigned(0xAC, &config), "0b1010_1100");
+    /// let config: RadixConfig = RadixConfig::base62();
+    /// assert_eq!(RadixEncoder::encode_unsigned(u64::MAX.into(), &config), "LygHa16AHYF");
+    /// ```
+    pub fn encode_unsigned(input: u128, config: &RadixConfig) -> String {
+        let radix: u128 = config.alphabet.len() as u128;
+        let mut input: u128 = input;
+        let mut digits: Vec<u8> = Vec::new();
+        while input != 0 || digits.len() < config.width.max(1) {
+            digits.push(config.alphabet[(input % radix) as usize]);
+            input /= radix;
+        }
+        let mut output: String = String::new();
+        if config.prefix {
+            match (config.custom, config.alphabet.len()) {
+                (false, 2) => output.push_str("0b"),
+                (false, 8) => output.push_str("0o"),
+                (false, 16) => output.push_str("0x"),
+                _ => {}
+            }
+        }
+        for (x, digit) in digits.iter().enumerate().rev() {
+            output.push(*digit as char);
+            if config.group != 0 && x != 0 && x % config.group == 0 {
+                output.push(config.separator);
+            }
+        }
+        return output;
+    }
+
+    /// 编码有符号整数, 负数以'-'开头, 其它有符号整数可以用`.into()`转成[i128].
+    pub fn encode_signed(input: i128, config: &RadixConfig) -> String {
+        let output: String = Self::encode_unsigned(input.unsigned_abs(), config);
+        if input < 0 {
+            return format!("-{}", output);
+        } else {
+            return output;
+        }
+    }
+
+    /// 解码无符号整数, 忽略分隔符, ' '和'_', 以及标准字母表的'0b', '0o', '0x'前缀.
+    ///
+    /// 标准字母表不区分大小写, 自定义字母表区分大小写.
+    ///
+    /// # Example
+    /// ```
+    /// use iceyee_encoder::RadixConfig;
+    /// use iceyee_encoder::RadixEncoder;
+    ///
+    /// let config: RadixConfig = RadixConfig::new(16).unwrap();
+    /// assert_eq!(RadixEncoder::decode_unsigned::<u8>("0xFF", &config), Ok(0xFF));
+    /// assert!(RadixEncoder::decode_unsigned::<u8>("0x100", &config).is_err());
+    /// ```
+    ///
+    /// - @exception 没有数字.
+    /// - @exception 出现未预期的字符.
+    /// - @exception 数值溢出.
+    pub fn decode_unsigned<T>(input: &str, config: &RadixConfig) -> Result<T, String>
+    where
+        T: TryFrom<u128>,
+    {
+        let output: u128 = Self::decode_magnitude(input, config)?;
+        return T::try_from(output).map_err(|_| iceyee_error::c!("数值溢出"));
+    }
+
+    /// 解码有符号整数, 可以用'-'或'+'开头, 其它同[RadixEncoder::decode_unsigned].
+    ///
+    /// - @exception 没有数字.
+    /// - @exception 出现未预期的字符.
+    /// - @exception 数值溢出.
+    pub fn decode_signed<T>(input: &str, config: &RadixConfig) -> Result<T, String>
+    where
+        T: TryFrom<i128>,
+    {
+        let input: &str = input.trim();
+        let (negative, input): (bool, &str) = if let Some(input) = input.strip_prefix('-') {
+            (true, input)
+        } else if let Some(input) = input.strip_prefix('+') {
+            (false, input)
+        } else {
+            (false, input)
+        };
+        let magnitude: u128 = Self::decode_magnitude(input, config)?;
+        let output: i128 = if negative {
+            0_i128
+                .checked_sub_unsigned(magnitude)
+                .ok_or_else(|| iceyee_error::c!("数值溢出"))?
+        } else {
+            i128::try_from(magnitude).map_err(|_| iceyee_error::c!("数值溢出"))?
+        };
+        return T::try_from(output).map_err(|_| iceyee_error::c!("数值溢出"));
+    }
+
+    fn decode_magnitude(input: &str, config: &RadixConfig) -> Result<u128, String> {
+        let radix: u128 = config.alphabet.len() as u128;
+        let mut input: &str = input.trim();
+        if !config.custom {
+            let prefix: Option<&str> = match config.alphabet.len() {
+                2 => Some("0b"),
+                8 => Some("0o"),
+                16 => Some("0x"),
+                _ => None,
+            };
+            if let Some(prefix) = prefix {
+                input = input.strip_prefix(prefix).unwrap_or(input);
+            }
+        }
+        let mut output: u128 = 0;
+        let mut count: usize = 0;
+        for c in input.chars() {
+            if c == config.separator || c == '_' || c == ' ' {
+                continue;
+            }
+            let position: Option<usize> = if config.custom {
+                config.alphabet.iter().position(|x| *x as char == c)
+            } else {
+                c.to_digit(radix as u32).map(|x| x as usize)
+            };
+            let digit: u128 = match position {
+                Some(digit) => digit as u128,
+                None => return Err(iceyee_error::c!("出现未预期的字符")),
+            };
+            output = output
+                .checked_mul(radix)
+                .and_then(|x| x.checked_add(digit))
+                .ok_or_else(|| iceyee_error::c!("数值溢出"))?;
+            count += 1;
+        }
+        if count == 0 {
+            return Err(iceyee_error::c!("没有数字"));
+        }
+        return Ok(output);
+    }
+
+    pub fn u64_to_bin(input: u64) -> String {
+        let mut input: u64 = input;
+        let mut output: String = String::new();
+        for _x in 0..64 {
+            let y: u8 = input as u8 & 0b1;
+            input >>= 1;
+            output.push((y + b'0') as char);
+        }
+        return output.chars().rev().collect();
     }
 
     pub fn u64_to_bin_(input: u64) -> String {
         let mut input: u64 = input;
         let mut output: String = String::new();
-        for x in 0..64 {
-            if x != 0 && x % 8 == 0 {
-                output.push('_');
+        for x in 0..64 {
+            if x != 0 && x % 8 == 0 {
+                output.push('_');
+            }
+            let y: u8 = input as u8 & 0b1;
+            input >>= 1;
+            output.push((y + b'0') as char);
+        }
+        output.push('b');
+        output.push('0');
+        return output.chars().rev().collect();
+    }
+
+    pub fn u32_to_bin(input: u32) -> String {
+        let mut input: u32 = input;
+        let mut output: String = String::new();
+        for _x in 0..32 {
+            let y: u8 = input as u8 & 0b1;
+            input >>= 1;
+            output.push((y + b'0') as char);
+        }
+        return output.chars().rev().collect();
+    }
+
+    pub fn u32_to_bin_(input: u32) -> String {
+        let mut input: u32 = input;
+        let mut output: String = String::new();
+        for x in 0..32 {
+            if x != 0 && x % 8 == 0 {
+                output.push('_');
+            }
+            let y: u8 = input as u8 & 0b1;
+            input >>= 1;
+            output.push((y + b'0') as char);
+        }
+        output.push('b');
+        output.push('0');
+        return output.chars().rev().collect();
+    }
+
+    pub fn u16_to_bin(input: u16) -> String {
+        let mut input: u16 = input;
+        let mut output: String = String::new();
+        for _x in 0..16 {
+            let y: u8 = input as u8 & 0b1;
+            input >>= 1;
+            output.push((y + b'0') as char);
+        }
+        return output.chars().rev().collect();
+    }
+
+    pub fn u16_to_bin_(input: u16) -> String {
+        let mut input: u16 = input;
+        let mut output: String = String::new();
+        for x in 0..16 {
+            if x != 0 && x % 8 == 0 {
+                output.push('_');
+            }
+            let y: u8 = input as u8 & 0b1;
+            input >>= 1;
+            output.push((y + b'0') as char);
+        }
+        output.push('b');
+        output.push('0');
+        return output.chars().rev().collect();
+    }
+
+    pub fn u8_to_bin(input: u8) -> String {
+        let mut input: u8 = input;
+        let mut output: String = String::new();
+        for _x in 0..8 {
+            let y: u8 = input as u8 & 0b1;
+            input >>= 1;
+            output.push((y + b'0') as char);
+        }
+        return output.chars().rev().collect();
+    }
+
+    pub fn u8_to_bin_(input: u8) -> String {
+        let mut input: u8 = input;
+        let mut output: String = String::new();
+        for x in 0..8 {
+            if x != 0 && x % 8 == 0 {
+                output.push('_');
+            }
+            let y: u8 = input as u8 & 0b1;
+            input >>= 1;
+            output.push((y + b'0') as char);
+        }
+        output.push('b');
+        output.push('0');
+        return output.chars().rev().collect();
+    }
+
+    pub fn u64_to_oct(input: u64) -> String {
+        let mut input: u64 = input;
+        let mut output: String = String::new();
+        for _x in 0..22 {
+            let y: u8 = input as u8 & 0b111;
+            input >>= 3;
+            output.push((y + b'0') as char);
+        }
+        return output.chars().rev().collect();
+    }
+
+    pub fn u64_to_oct_(input: u64) -> String {
+        let mut input: u64 = input;
+        let mut output: String = String::new();
+        for x in 0..22 {
+            if x != 0 && false {
+                output.push('_');
+            }
+            let y: u8 = input as u8 & 0b111;
+            input >>= 3;
+            output.push((y + b'0') as char);
+        }
+        output.push('o');
+        output.push('0');
+        return output.chars().rev().collect();
+    }
+
+    pub fn u32_to_oct(input: u32) -> String {
+        let mut input: u32 = input;
+        let mut output: String = String::new();
+        for _x in 0..11 {
+            let y: u8 = input as u8 & 0b111;
+            input >>= 3;
+            output.push((y + b'0') as char);
+        }
+        return output.chars().rev().collect();
+    }
+
+    pub fn u32_to_oct_(input: u32) -> String {
+        let mut input: u32 = input;
+        let mut output: String = String::new();
+        for x in 0..11 {
+            if x != 0 && false {
+                output.push('_');
+            }
+            let y: u8 = input as u8 & 0b111;
+            input >>= 3;
+            output.push((y + b'0') as char);
+        }
+        output.push('o');
+        output.push('0');
+        return output.chars().rev().collect();
+    }
+
+    pub fn u16_to_oct(input: u16) -> String {
+        let mut input: u16 = input;
+        let mut output: String = String::new();
+        for _x in 0..6 {
+            let y: u8 = input as u8 & 0b111;
+            input >>= 3;
+            output.push((y + b'0') as char);
+        }
+        return output.chars().rev().collect();
+    }
+
+    pub fn u16_to_oct_(input: u16) -> String {
+        let mut input: u16 = input;
+        let mut output: String = String::new();
+        for x in 0..6 {
+            if x != 0 && false {
+                output.push('_');
+            }
+            let y: u8 = input as u8 & 0b111;
+            input >>= 3;
+            output.push((y + b'0') as char);
+        }
+        output.push('o');
+        output.push('0');
+        return output.chars().rev().collect();
+    }
+
+    pub fn u8_to_oct(input: u8) -> String {
+        let mut input: u8 = input;
+        let mut output: String = String::new();
+        for _x in 0..3 {
+            let y: u8 = input as u8 & 0b111;
+            input >>= 3;
+            output.push((y + b'0') as char);
+        }
+        return output.chars().rev().collect();
+    }
+
+    pub fn u8_to_oct_(input: u8) -> String {
+        let mut input: u8 = input;
+        let mut output: String = String::new();
+        for x in 0..3 {
+            if x != 0 && false {
+                output.push('_');
+            }
+            let y: u8 = input as u8 & 0b111;
+            input >>= 3;
+            output.push((y + b'0') as char);
+        }
+        output.push('o');
+        output.push('0');
+        return output.chars().rev().collect();
+    }
+
+    pub fn u64_to_hex(input: u64) -> String {
+        let mut input: u64 = input;
+        let mut output: String = String::new();
+        for _x in 0..16 {
+            let y: u8 = input as u8 & 0xF;
+            input >>= 4;
+            if y < 10 {
+                output.push((y + b'0') as char);
+            } else {
+                output.push((y - 10 + b'A') as char);
+            }
+        }
+        return output.chars().rev().collect();
+    }
+
+    pub fn u64_to_hex_(input: u64) -> String {
+        let mut input: u64 = input;
+        let mut output: String = String::new();
+        for x in 0..16 {
+            if x != 0 && x % 4 == 0 {
+                output.push('_');
+            }
+            let y: u8 = input as u8 & 0xF;
+            input >>= 4;
+            if y < 10 {
+                output.push((y + b'0') as char);
+            } else {
+                output.push((y - 10 + b'A') as char);
+            }
+        }
+        output.push('x');
+        output.push('0');
+        return output.chars().rev().collect();
+    }
+
+    pub fn u32_to_hex(input: u32) -> String {
+        let mut input: u32 = input;
+        let mut output: String = String::new();
+        for _x in 0..8 {
+            let y: u8 = input as u8 & 0xF;
+            input >>= 4;
+            if y < 10 {
+                output.push((y + b'0') as char);
+            } else {
+                output.push((y - 10 + b'A') as char);
+            }
+        }
+        return output.chars().rev().collect();
+    }
+
+    pub fn u32_to_hex_(input: u32) -> String {
+        let mut input: u32 = input;
+        let mut output: String = String::new();
+        for x in 0..8 {
+            if x != 0 && x % 4 == 0 {
+                output.push('_');
+            }
+            let y: u8 = input as u8 & 0xF;
+            input >>= 4;
+            if y < 10 {
+                output.push((y + b'0') as char);
+            } else {
+                output.push((y - 10 + b'A') as char);
+            }
+        }
+        output.push('x');
+        output.push('0');
+        return output.chars().rev().collect();
+    }
+
+    pub fn u16_to_hex(input: u16) -> String {
+        let mut input: u16 = input;
+        let mut output: String = String::new();
+        for _x in 0..4 {
+            let y: u8 = input as u8 & 0xF;
+            input >>= 4;
+            if y < 10 {
+                output.push((y + b'0') as char);
+            } else {
+                output.push((y - 10 + b'A') as char);
+            }
+        }
+        return output.chars().rev().collect();
+    }
+
+    pub fn u16_to_hex_(input: u16) -> String {
+        let mut input: u16 = input;
+        let mut output: String = String::new();
+        for x in 0..4 {
+            if x != 0 && x % 4 == 0 {
+                output.push('_');
+            }
+            let y: u8 = input as u8 & 0xF;
+            input >>= 4;
+            if y < 10 {
+                output.push((y + b'0') as char);
+            } else {
+                output.push((y - 10 + b'A') as char);
+            }
+        }
+        output.push('x');
+        output.push('0');
+        return output.chars().rev().collect();
+    }
+
+    pub fn u8_to_hex(input: u8) -> String {
+        let mut input: u8 = input;
+        let mut output: String = String::new();
+        for _x in 0..2 {
+            let y: u8 = input as u8 & 0xF;
+            input >>= 4;
+            if y < 10 {
+                output.push((y + b'0') as char);
+            } else {
+                output.push((y - 10 + b'A') as char);
+            }
+        }
+        return output.chars().rev().collect();
+    }
+
+    pub fn u8_to_hex_(input: u8) -> String {
+        let mut input: u8 = input;
+        let mut output: String = String::new();
+        for x in 0..2 {
+            if x != 0 && x % 4 == 0 {
+                output.push('_');
+            }
+            let y: u8 = input as u8 & 0xF;
+            input >>= 4;
+            if y < 10 {
+                output.push((y + b'0') as char);
+            } else {
+                output.push((y - 10 + b'A') as char);
+            }
+        }
+        output.push('x');
+        output.push('0');
+        return output.chars().rev().collect();
+    }
+}
+
+/// LEB128变长整数, 每个字节的低7位是数据, 最高位表示后面还有字节, 低位在前.
+///
+/// 有符号整数先做zigzag编码, 0, -1, 1, -2分别对应0, 1, 2, 3, 绝对值小的数编码后也短.
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::VarintEncoder;
+///
+/// assert_eq!(VarintEncoder::encode_unsigned(300), vec![0xAC, 0x02]);
+/// assert_eq!(VarintEncoder::encode_signed(-2), vec![0x03]);
+/// assert_eq!(VarintEncoder::decode_unsigned(&[0xAC, 0x02, 0xFF]).unwrap(), (300, 2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct VarintEncoder;
+
+impl VarintEncoder {
+    /// 编码无符号整数, 最多10个字节.
+    pub fn encode_unsigned(input: u64) -> Vec<u8> {
+        let mut output: Vec<u8> = Vec::with_capacity(10);
+        Self::write_unsigned(input, &mut output);
+        return output;
+    }
+
+    /// 编码有符号整数, zigzag, 最多10个字节.
+    pub fn encode_signed(input: i64) -> Vec<u8> {
+        return Self::encode_unsigned(Self::zigzag_encode(input));
+    }
+
+    /// 编码无符号整数, 追加到output.
+    pub fn write_unsigned(input: u64, output: &mut Vec<u8>) {
+        let mut input: u64 = input;
+        while 0x80 <= input {
+            output.push((input & 0x7F) as u8 | 0x80);
+            input >>= 7;
+        }
+        output.push(input as u8);
+        return;
+    }
+
+    /// 编码有符号整数, zigzag, 追加到output.
+    pub fn write_signed(input: i64, output: &mut Vec<u8>) {
+        Self::write_unsigned(Self::zigzag_encode(input), output);
+        return;
+    }
+
+    /// 解码开头的一个无符号整数, 返回数值和占用的字节数.
+    ///
+    /// - @exception 无效的长度, 数据不完整.
+    /// - @exception 数值溢出, 超过64位.
+    pub fn decode_unsigned(input: &[u8]) -> Result<(u64, usize), String> {
+        return match Self::decode_partial(input)? {
+            Some(x) => Ok(x),
+            None => Err(iceyee_error::c!("无效的长度")),
+        };
+    }
+
+    /// 解码开头的一个有符号整数, zigzag, 返回数值和占用的字节数.
+    ///
+    /// - @exception 无效的长度, 数据不完整.
+    /// - @exception 数值溢出, 超过64位.
+    pub fn decode_signed(input: &[u8]) -> Result<(i64, usize), String> {
+        let (value, length): (u64, usize) = Self::decode_unsigned(input)?;
+        return Ok((Self::zigzag_decode(value), length));
+    }
+
+    /// 与[VarintEncoder::decode_unsigned]相同, 但是数据不完整时返回[None].
+    fn decode_partial(input: &[u8]) -> Result<Option<(u64, usize)>, String> {
+        let mut value: u64 = 0;
+        for (x, byte) in input.iter().enumerate() {
+            /* 第10个字节只剩1位. */
+            if x == 9 && 1 < *byte {
+                return Err(iceyee_error::c!("数值溢出"));
+            }
+            value |= ((byte & 0x7F) as u64) << (x * 7);
+            if byte & 0x80 == 0 {
+                return Ok(Some((value, x + 1)));
+            }
+        }
+        return Ok(None);
+    }
+
+    /// zigzag编码, 符号位移到最低位.
+    pub fn zigzag_encode(input: i64) -> u64 {
+        return ((input << 1) ^ (input >> 63)) as u64;
+    }
+
+    /// zigzag解码.
+    pub fn zigzag_decode(input: u64) -> i64 {
+        return (input >> 1) as i64 ^ -((input & 1) as i64);
+    }
+}
+
+/// 生成[BinaryReader]读定长数字的方法.
+macro_rules! binary_read {
+    ($($t:ty, $be:ident, $le:ident;)*) => {
+        $(
+            /// 读大端.
+            ///
+            /// - @exception 无效的长度, 剩余的数据不足.
+            pub fn $be(&mut self) -> Result<$t, String> {
+                let bytes: &[u8] = self.read_bytes(std::mem::size_of::<$t>())?;
+                return Ok(<$t>::from_be_bytes(bytes.try_into().expect("NEVER")));
+            }
+
+            /// 读小端.
+            ///
+            /// - @exception 无效的长度, 剩余的数据不足.
+            pub fn $le(&mut self) -> Result<$t, String> {
+                let bytes: &[u8] = self.read_bytes(std::mem::size_of::<$t>())?;
+                return Ok(<$t>::from_le_bytes(bytes.try_into().expect("NEVER")));
+            }
+        )*
+    };
+}
+
+/// 生成[BinaryWriter]写定长数字的方法.
+macro_rules! binary_write {
+    ($($t:ty, $be:ident, $le:ident;)*) => {
+        $(
+            /// 写大端.
+            pub fn $be(&mut self, value: $t) {
+                self.output.extend_from_slice(&value.to_be_bytes());
+                return;
+            }
+
+            /// 写小端.
+            pub fn $le(&mut self, value: $t) {
+                self.output.extend_from_slice(&value.to_le_bytes());
+                return;
+            }
+        )*
+    };
+}
+
+/// 从字节读出定长数字和[VarintEncoder]的变长整数, 每次读完位置后移.
+///
+/// 失败时位置不变.
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::BinaryReader;
+///
+/// let mut reader: BinaryReader = BinaryReader::new(&[0x12, 0x34, 0x34, 0x12, 0xAC, 0x02]);
+/// assert_eq!(reader.read_u16_be().unwrap(), 0x1234);
+/// assert_eq!(reader.read_u16_le().unwrap(), 0x1234);
+/// assert_eq!(reader.read_varint_unsigned().unwrap(), 300);
+/// assert!(reader.read_u8().is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BinaryReader<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BinaryReader<'a> {
+    pub fn new(input: &'a [u8]) -> Self {
+        return Self {
+            input: input,
+            position: 0,
+        };
+    }
+
+    /// 已经读了的字节数.
+    pub fn position(&self) -> usize {
+        return self.position;
+    }
+
+    /// 剩余的字节数.
+    pub fn remaining(&self) -> usize {
+        return self.input.len() - self.position;
+    }
+
+    /// 剩余的字节, 不移动位置.
+    pub fn rest(&self) -> &'a [u8] {
+        return &self.input[self.position..];
+    }
+
+    /// 读length个字节.
+    ///
+    /// - @exception 无效的长度, 剩余的数据不足.
+    pub fn read_bytes(&mut self, length: usize) -> Result<&'a [u8], String> {
+        if self.remaining() < length {
+            return Err(iceyee_error::c!("无效的长度"));
+        }
+        let bytes: &'a [u8] = &self.input[self.position..self.position + length];
+        self.position += length;
+        return Ok(bytes);
+    }
+
+    /// - @exception 无效的长度, 剩余的数据不足.
+    pub fn read_u8(&mut self) -> Result<u8, String> {
+        return Ok(self.read_bytes(1)?[0]);
+    }
+
+    /// - @exception 无效的长度, 剩余的数据不足.
+    pub fn read_i8(&mut self) -> Result<i8, String> {
+        return Ok(self.read_bytes(1)?[0] as i8);
+    }
+
+    binary_read! {
+        u16, read_u16_be, read_u16_le;
+        u32, read_u32_be, read_u32_le;
+        u64, read_u64_be, read_u64_le;
+        u128, read_u128_be, read_u128_le;
+        i16, read_i16_be, read_i16_le;
+        i32, read_i32_be, read_i32_le;
+        i64, read_i64_be, read_i64_le;
+        i128, read_i128_be, read_i128_le;
+        f32, read_f32_be, read_f32_le;
+        f64, read_f64_be, read_f64_le;
+    }
+
+    /// 读无符号的变长整数.
+    ///
+    /// - @exception 无效的长度, 数据不完整.
+    /// - @exception 数值溢出, 超过64位.
+    pub fn read_varint_unsigned(&mut self) -> Result<u64, String> {
+        let (value, length): (u64, usize) = VarintEncoder::decode_unsigned(self.rest())?;
+        self.position += length;
+        return Ok(value);
+    }
+
+    /// 读有符号的变长整数, zigzag.
+    ///
+    /// - @exception 无效的长度, 数据不完整.
+    /// - @exception 数值溢出, 超过64位.
+    pub fn read_varint_signed(&mut self) -> Result<i64, String> {
+        let (value, length): (i64, usize) = VarintEncoder::decode_signed(self.rest())?;
+        self.position += length;
+        return Ok(value);
+    }
+}
+
+/// 把定长数字和[VarintEncoder]的变长整数写成字节, 与[BinaryReader]对应.
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::BinaryWriter;
+///
+/// let mut writer: BinaryWriter = BinaryWriter::new();
+/// writer.write_u16_be(0x1234);
+/// writer.write_u16_le(0x1234);
+/// writer.write_varint_unsigned(300);
+/// assert_eq!(writer.into_inner(), vec![0x12, 0x34, 0x34, 0x12, 0xAC, 0x02]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BinaryWriter {
+    output: Vec<u8>,
+}
+
+impl BinaryWriter {
+    pub fn new() -> Self {
+        return Self { output: Vec::new() };
+    }
+
+    /// 在已有的数据后面追加.
+    pub fn from_vec(output: Vec<u8>) -> Self {
+        return Self { output: output };
+    }
+
+    pub fn len(&self) -> usize {
+        return self.output.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.output.is_empty();
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        return &self.output;
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        return self.output;
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.output.extend_from_slice(bytes);
+        return;
+    }
+
+    pub fn write_u8(&mut self, value: u8) {
+        self.output.push(value);
+        return;
+    }
+
+    pub fn write_i8(&mut self, value: i8) {
+        self.output.push(value as u8);
+        return;
+    }
+
+    binary_write! {
+        u16, write_u16_be, write_u16_le;
+        u32, write_u32_be, write_u32_le;
+        u64, write_u64_be, write_u64_le;
+        u128, write_u128_be, write_u128_le;
+        i16, write_i16_be, write_i16_le;
+        i32, write_i32_be, write_i32_le;
+        i64, write_i64_be, write_i64_le;
+        i128, write_i128_be, write_i128_le;
+        f32, write_f32_be, write_f32_le;
+        f64, write_f64_be, write_f64_le;
+    }
+
+    /// 写无符号的变长整数.
+    pub fn write_varint_unsigned(&mut self, value: u64) {
+        VarintEncoder::write_unsigned(value, &mut self.output);
+        return;
+    }
+
+    /// 写有符号的变长整数, zigzag.
+    pub fn write_varint_signed(&mut self, value: i64) {
+        VarintEncoder::write_signed(value, &mut self.output);
+        return;
+    }
+}
+
+/// [TlvEncoder::decode_frame]解码出的一帧.
+///
+/// - tag, 类型.
+/// - value, 值, 借用自输入.
+/// - length, 整个帧占用的字节数, 包括类型和长度.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TlvFrame<'a> {
+    pub tag: u64,
+    pub value: &'a [u8],
+    pub length: usize,
+}
+
+/// TLV分帧, 类型和长度都是[VarintEncoder]的无符号变长整数, 后面是值.
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::TlvEncoder;
+///
+/// let mut output: Vec<u8> = TlvEncoder::encode(1, "hi".as_bytes());
+/// TlvEncoder::write(300, &[], &mut output);
+/// assert_eq!(output, vec![0x01, 0x02, b'h', b'i', 0xAC, 0x02, 0x00]);
+/// let frames: Vec<(u64, Vec<u8>)> = TlvEncoder::decode(&output).unwrap();
+/// assert_eq!(frames, vec![(1, b"hi".to_vec()), (300, vec![])]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TlvEncoder;
+
+impl TlvEncoder {
+    /// 编码一帧.
+    pub fn encode(tag: u64, value: &[u8]) -> Vec<u8> {
+        let mut output: Vec<u8> = Vec::with_capacity(value.len() + 20);
+        Self::write(tag, value, &mut output);
+        return output;
+    }
+
+    /// 编码一帧, 追加到output.
+    pub fn write(tag: u64, value: &[u8], output: &mut Vec<u8>) {
+        VarintEncoder::write_unsigned(tag, output);
+        VarintEncoder::write_unsigned(value.len() as u64, output);
+        output.extend_from_slice(value);
+        return;
+    }
+
+    /// 解码开头的一帧, 返回类型, 值和占用的字节数, 用于从流里分帧.
+    ///
+    /// 数据不完整时返回[None], 等收到更多的数据再解码.
+    ///
+    /// - @exception 数值溢出, 类型或长度超过64位.
+    pub fn decode_frame(input: &[u8]) -> Result<Option<TlvFrame<'_>>, String> {
+        let Some((tag, a)) = VarintEncoder::decode_partial(input)? else {
+            return Ok(None);
+        };
+        let Some((length, b)) = VarintEncoder::decode_partial(&input[a..])? else {
+            return Ok(None);
+        };
+        let start: usize = a + b;
+        if ((input.len() - start) as u64) < length {
+            return Ok(None);
+        }
+        let end: usize = start + length as usize;
+        return Ok(Some(TlvFrame {
+            tag: tag,
+            value: &input[start..end],
+            length: end,
+        }));
+    }
+
+    /// 解码全部的帧.
+    ///
+    /// - @exception 无效的长度, 最后一帧不完整.
+    /// - @exception 数值溢出, 类型或长度超过64位.
+    pub fn decode(input: &[u8]) -> Result<Vec<(u64, Vec<u8>)>, String> {
+        let mut output: Vec<(u64, Vec<u8>)> = Vec::new();
+        let mut input: &[u8] = input;
+        while !input.is_empty() {
+            match Self::decode_frame(input)? {
+                Some(frame) => {
+                    output.push((frame.tag, frame.value.to_vec()));
+                    input = &input[frame.length..];
+                }
+                None => return Err(iceyee_error::c!("无效的长度")),
+            }
+        }
+        return Ok(output);
+    }
+}
+
+/// Url编码.
+
+#[derive(Debug, Clone)]
+pub struct UrlEncoder;
+
+impl UrlEncoder {
+    /// 编码.
+    pub fn encode(input: &str) -> String {
+        return Encoding::encode(&UrlEncoder, input.as_bytes());
+    }
+
+    /// 按Url的组成部分编码, 除了[Form](UrlEncodeSet::Form), 空格都编码成'%20'.
+    ///
+    /// # Example
+    /// ```
+    /// use iceyee_encoder::UrlEncodeSet;
+    /// use iceyee_encoder::UrlEncoder;
+    ///
+    /// assert_eq!(UrlEncoder::encode_component("a b/c", UrlEncodeSet::PathSegment), "a%20b%2Fc");
+    /// assert_eq!(UrlEncoder::encode_component("a+b=c", UrlEncodeSet::Query), "a%2Bb%3Dc");
+    /// assert_eq!(UrlEncoder::encode_component("a b~", UrlEncodeSet::Form), "a+b%7E");
+    /// ```
+    pub fn encode_component(input: &str, set: UrlEncodeSet) -> String {
+        static TABLE: [char; 16] = [
+            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
+        ];
+        let reserved: &str = match set {
+            UrlEncodeSet::PathSegment => "-._~!$&'()*+,;=:@",
+            UrlEncodeSet::Query => "-._~!$'()*,;:@/?",
+            UrlEncodeSet::Fragment => "-._~!$&'()*+,;=:@/?",
+            UrlEncodeSet::Userinfo => "-._~!$&'()*+,;=",
+            UrlEncodeSet::Form => "*-._",
+        };
+        let mut output: String = String::new();
+        for x in input.as_bytes() {
+            if *x == b' ' && set == UrlEncodeSet::Form {
+                output.push('+');
+            } else if x.is_ascii_alphanumeric() || reserved.contains(*x as char) {
+                output.push(*x as char);
+            } else {
+                let high: u8 = (*x >> 4) & 0x0F;
+                let low: u8 = (*x >> 0) & 0x0F;
+                output.push('%');
+                output.push(TABLE[high as usize]);
+                output.push(TABLE[low as usize]);
+            }
+        }
+        return output;
+    }
+
+    /// 解码, '+'当作空格.
+    ///
+    /// - @exception 错误的格式.
+    /// - @exception 内容不是UTF-8编码.
+    pub fn decode(cipher: &str) -> Result<String, String> {
+        return Self::decode_with(cipher, true);
+    }
+
+    /// 解码, plus_as_space决定'+'是否当作空格.
+    ///
+    /// 只有application/x-www-form-urlencoded和查询参数里的'+'才是空格, 路径里的'+'就是'+'.
+    ///
+    /// - @exception 错误的格式.
+    /// - @exception 内容不是UTF-8编码.
+    pub fn decode_with(cipher: &str, plus_as_space: bool) -> Result<String, String> {
+        let mut plain: Vec<u8> = Vec::with_capacity(cipher.len());
+        Self::decode_bytes(cipher.as_bytes(), plus_as_space, &mut plain)?;
+        let plain: String =
+            String::from_utf8(plain).map_err(|_| iceyee_error::c!("内容不是UTF-8编码"))?;
+        return Ok(plain);
+    }
+
+    /// 解码, 追加到plain, 不检查UTF-8.
+    ///
+    /// - @exception 错误的格式.
+    fn decode_bytes(cipher: &[u8], plus_as_space: bool, plain: &mut Vec<u8>) -> Result<(), String> {
+        enum Status {
+            Normal,
+            High,
+            Low,
+        }
+        let mut status: Status = Status::Normal;
+        let mut high: u8 = 0;
+        let mut low: u8;
+        for x in cipher {
+            match status {
+                Status::Normal => match *x {
+                    b'%' => status = Status::High,
+                    b'+' if plus_as_space => plain.push(b' '),
+                    _ => plain.push(*x),
+                },
+                Status::High => match *x {
+                    b'0'..=b'9' => {
+                        status = Status::Low;
+                        high = *x - b'0';
+                    }
+                    b'a'..=b'f' => {
+                        status = Status::Low;
+                        high = *x - b'a' + 10;
+                    }
+                    b'A'..=b'F' => {
+                        status = Status::Low;
+                        high = *x - b'A' + 10;
+                    }
+                    _ => return Err(iceyee_error::c!("错误的格式")),
+                },
+                Status::Low => match *x {
+                    b'0'..=b'9' => {
+                        status = Status::Normal;
+                        low = *x - b'0';
+                        plain.push((high << 4) | (low << 0));
+                    }
+                    b'a'..=b'f' => {
+                        status = Status::Normal;
+                        low = *x - b'a' + 10;
+                        plain.push((high << 4) | (low << 0));
+                    }
+                    b'A'..=b'F' => {
+                        status = Status::Normal;
+                        low = *x - b'A' + 10;
+                        plain.push((high << 4) | (low << 0));
+                    }
+                    _ => return Err(iceyee_error::c!("错误的格式")),
+                },
+            }
+        }
+        match status {
+            Status::Normal => {}
+            _ => return Err(iceyee_error::c!("错误的格式")),
+        }
+        return Ok(());
+    }
+}
+
+impl Encoding for UrlEncoder {
+    fn name(&self) -> &'static str {
+        return "url";
+    }
+
+    fn encoded_length(&self, length: usize) -> usize {
+        return length * 3;
+    }
+
+    fn decoded_length(&self, length: usize) -> usize {
+        return length;
+    }
+
+    fn encode_to(&self, input: &[u8], output: &mut String) {
+        static TABLE: [char; 16] = [
+            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
+        ];
+        for x in input {
+            if *x == b' ' {
+                output.push('+');
+            } else if x.is_ascii_alphanumeric() || "$-_.".contains(*x as char) {
+                output.push(*x as char);
+            } else {
+                let high: u8 = (*x >> 4) & 0x0F;
+                let low: u8 = (*x >> 0) & 0x0F;
+                output.push('%');
+                output.push(TABLE[high as usize]);
+                output.push(TABLE[low as usize]);
+            }
+        }
+        return;
+    }
+
+    /// '+'当作空格, 不要求是UTF-8.
+    fn decode_to(&self, input: &str, output: &mut Vec<u8>) -> Result<(), String> {
+        return UrlEncoder::decode_bytes(input.as_bytes(), true, output);
+    }
+}
+
+/// HTML实体编码, 用于把文本放进HTML, 防止XSS.
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::HtmlEncoder;
+///
+/// let output: String = HtmlEncoder::encode("<a href=\"x\">'&'</a>");
+/// assert_eq!(output, "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;");
+/// assert_eq!(HtmlEncoder::decode("&lt;&#x4F60;&#22909;&nbsp;&copy;&gt;"), "<你好\u{A0}©>");
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtmlEncoder;
+
+impl HtmlEncoder {
+    /// 常用的命名实体.
+    const ENTITIES: [(&str, char); 32] = [
+        ("amp", '&'),
+        ("lt", '<'),
+        ("gt", '>'),
+        ("quot", '"'),
+        ("apos", '\''),
+        ("nbsp", '\u{A0}'),
+        ("iexcl", '¡'),
+        ("cent", '¢'),
+        ("pound", '£'),
+        ("yen", '¥'),
+        ("sect", '§'),
+        ("copy", '©'),
+        ("laquo", '«'),
+        ("reg", '®'),
+        ("deg", '°'),
+        ("plusmn", '±'),
+        ("middot", '·'),
+        ("raquo", '»'),
+        ("times", '×'),
+        ("divide", '÷'),
+        ("ndash", '–'),
+        ("mdash", '—'),
+        ("lsquo", '‘'),
+        ("rsquo", '’'),
+        ("ldquo", '“'),
+        ("rdquo", '”'),
+        ("bull", '•'),
+        ("hellip", '…'),
+        ("euro", '€'),
+        ("trade", '™'),
+        ("larr", '←'),
+        ("rarr", '→'),
+    ];
+
+    /// 编码'&', '<', '>', '"'和单引号, 可以用于元素的内容和属性值.
+    pub fn encode(input: &str) -> String {
+        let mut output: String = String::with_capacity(input.len());
+        for c in input.chars() {
+            match c {
+                '&' => output.push_str("&amp;"),
+                '<' => output.push_str("&lt;"),
+                '>' => output.push_str("&gt;"),
+                '"' => output.push_str("&quot;"),
+                '\'' => output.push_str("&#39;"),
+                _ => output.push(c),
+            }
+        }
+        return output;
+    }
+
+    /// 解码命名实体和数字实体, 比如'&amp;', '&#39;', '&#x27;'.
+    ///
+    /// 无法识别的实体保持不变, 没有异常.
+    pub fn decode(input: &str) -> String {
+        let mut output: String = String::with_capacity(input.len());
+        let mut rest: &str = input;
+        while let Some(x) = rest.find('&') {
+            output.push_str(&rest[..x]);
+            rest = &rest[x..];
+            /* 实体名最长32个字节, 只在这个范围内找';', 否则每个'&'都要扫描到结尾. */
+            let entity: Option<(char, usize)> = rest.as_bytes()[1..]
+                .iter()
+                .take(33)
+                .position(|x| *x == b';')
+                .and_then(|y| Self::entity(&rest[1..1 + y]).map(|c| (c, y + 2)));
+            match entity {
+                Some((c, length)) => {
+                    output.push(c);
+                    rest = &rest[length..];
+                }
+                None => {
+                    output.push('&');
+                    rest = &rest[1..];
+                }
+            }
+        }
+        output.push_str(rest);
+        return output;
+    }
+
+    fn entity(name: &str) -> Option<char> {
+        if let Some(number) = name.strip_prefix('#') {
+            let value: Option<u32> = if let Some(number) = number.strip_prefix(['x', 'X']) {
+                u32::from_str_radix(number, 16).ok()
+            } else {
+                number.parse::<u32>().ok()
+            };
+            return value.and_then(char::from_u32);
+        }
+        return Self::ENTITIES
+            .iter()
+            .find(|(x, _)| *x == name)
+            .map(|(_, c)| *c);
+    }
+}
+
+/// JSON字符串编码, 不包含两边的'"'.
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::JsonStringEncoder;
+///
+/// let output: String = JsonStringEncoder::encode("a\"b\\c\n</script>\u{1}");
+/// assert_eq!(output, "a\\\"b\\\\c\\n<\\/script>\\u0001");
+/// assert_eq!(JsonStringEncoder::decode("\\ud83d\\ude00\\u4f60").unwrap(), "😀你");
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonStringEncoder;
+
+impl JsonStringEncoder {
+    /// 编码'"', '\\', 控制字符, 以及'/', 防止在'<script>'里出现'</script>'.
+    ///
+    /// 非ASCII字符保持不变.
+    pub fn encode(input: &str) -> String {
+        let mut output: String = String::with_capacity(input.len());
+        for c in input.chars() {
+            match c {
+                '"' => output.push_str("\\\""),
+                '\\' => output.push_str("\\\\"),
+                '/' => output.push_str("\\/"),
+                '\u{08}' => output.push_str("\\b"),
+                '\u{0C}' => output.push_str("\\f"),
+                '\n' => output.push_str("\\n"),
+                '\r' => output.push_str("\\r"),
+                '\t' => output.push_str("\\t"),
+                '\u{00}'..='\u{1F}' | '\u{7F}' | '\u{2028}' | '\u{2029}' => {
+                    output.push_str(&format!("\\u{:04x}", c as u32));
+                }
+                _ => output.push(c),
+            }
+        }
+        return output;
+    }
+
+    /// 编码, 非ASCII字符也编码成'\\uXXXX', 超过U+FFFF的编码成代理对.
+    pub fn encode_ascii(input: &str) -> String {
+        let mut output: String = String::with_capacity(input.len());
+        for c in input.chars() {
+            if c.is_ascii() {
+                output.push_str(&Self::encode(c.encode_utf8(&mut [0; 4])));
+            } else {
+                for x in c.encode_utf16(&mut [0; 2]) {
+                    output.push_str(&format!("\\u{:04x}", x));
+                }
+            }
+        }
+        return output;
+    }
+
+    /// 解码, 支持'\\uXXXX'和代理对.
+    ///
+    /// - @exception 错误的格式.
+    /// - @exception 出现未预期的字符, 未编码的控制字符.
+    /// - @exception 无效的代理对.
+    pub fn decode(input: &str) -> Result<String, String> {
+        let mut output: String = String::with_capacity(input.len());
+        let mut chars = input.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                if c < '\u{20}' {
+                    return Err(iceyee_error::c!("出现未预期的字符"));
+                }
+                output.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('"') => output.push('"'),
+                Some('\\') => output.push('\\'),
+                Some('/') => output.push('/'),
+                Some('b') => output.push('\u{08}'),
+                Some('f') => output.push('\u{0C}'),
+                Some('n') => output.push('\n'),
+                Some('r') => output.push('\r'),
+                Some('t') => output.push('\t'),
+                Some('u') => {
+                    let high: u32 = Self::hex4(&mut chars)?;
+                    let value: u32 = if (0xD800..0xDC00).contains(&high) {
+                        /* 高位代理, 后面必须是低位代理. */
+                        if chars.next() != Some('\\') || chars.next() != Some('u') {
+                            return Err(iceyee_error::c!("无效的代理对"));
+                        }
+                        let low: u32 = Self::hex4(&mut chars)?;
+                        if !(0xDC00..0xE000).contains(&low) {
+                            return Err(iceyee_error::c!("无效的代理对"));
+                        }
+                        0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                    } else if (0xDC00..0xE000).contains(&high) {
+                        return Err(iceyee_error::c!("无效的代理对"));
+                    } else {
+                        high
+                    };
+                    output.push(char::from_u32(value).expect("NEVER"));
+                }
+                _ => return Err(iceyee_error::c!("错误的格式")),
+            }
+        }
+        return Ok(output);
+    }
+
+    fn hex4(chars: &mut std::str::Chars) -> Result<u32, String> {
+        let mut value: u32 = 0;
+        for _ in 0..4 {
+            match chars.next().and_then(|c| c.to_digit(16)) {
+                Some(x) => value = value << 4 | x,
+                None => return Err(iceyee_error::c!("错误的格式")),
+            }
+        }
+        return Ok(value);
+    }
+}
+
+/// Quoted-printable编码, RFC 2045, 用于邮件正文.
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::QuotedPrintableEncoder;
+///
+/// let output: String = QuotedPrintableEncoder::encode("你好 = hello".as_bytes());
+/// assert_eq!(output, "=E4=BD=A0=E5=A5=BD =3D hello");
+/// let input: Vec<u8> = QuotedPrintableEncoder::decode(&output).unwrap();
+/// assert_eq!(input, "你好 = hello".as_bytes());
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuotedPrintableEncoder;
+
+impl QuotedPrintableEncoder {
+    /// 编码.
+    ///
+    /// '\n'和'\r\n'是换行, 输出'\r\n', 行尾的空格和制表符会被编码,
+    /// 每行不超过76个字符, 超过的用'='软换行.
+    pub fn encode(input: &[u8]) -> String {
+        return Encoding::encode(&QuotedPrintableEncoder, input);
+    }
+
+    /// 解码, 去掉软换行和行尾的空白, 换行保持不变.
+    ///
+    /// - @exception 错误的格式.
+    pub fn decode(input: &str) -> Result<Vec<u8>, String> {
+        let mut output: Vec<u8> = Vec::with_capacity(input.len());
+        let mut line_break: &[u8] = b"";
+        for line in input.split('\n') {
+            output.extend_from_slice(line_break);
+            let (line, crlf): (&str, bool) = match line.strip_suffix('\r') {
+                Some(line) => (line, true),
+                None => (line, false),
+            };
+            let line: &str = line.trim_end_matches([' ', '\t']);
+            let (line, soft): (&str, bool) = match line.strip_suffix('=') {
+                Some(line) => (line, true),
+                None => (line, false),
+            };
+            let line: &[u8] = line.as_bytes();
+            let mut x: usize = 0;
+            while x < line.len() {
+                if line[x] != b'=' {
+                    output.push(line[x]);
+                    x += 1;
+                    continue;
+                }
+                let high: Option<u32> = line.get(x + 1).and_then(|c| (*c as char).to_digit(16));
+                let low: Option<u32> = line.get(x + 2).and_then(|c| (*c as char).to_digit(16));
+                match (high, low) {
+                    (Some(high), Some(low)) => output.push((high << 4 | low) as u8),
+                    _ => return Err(iceyee_error::c!("错误的格式")),
+                }
+                x += 3;
+            }
+            /* 软换行连同换行符一起去掉. */
+            line_break = if soft {
+                b""
+            } else if crlf {
+                b"\r\n"
+            } else {
+                b"\n"
+            };
+        }
+        return Ok(output);
+    }
+}
+
+impl Encoding for QuotedPrintableEncoder {
+    fn name(&self) -> &'static str {
+        return "quoted-printable";
+    }
+
+    fn encoded_length(&self, length: usize) -> usize {
+        /* 每个字节最多3个字符, 每行最多75个字符加上软换行'=\r\n'. */
+        let length: usize = length * 3;
+        return length + (length / 75 + 1) * 3;
+    }
+
+    fn decoded_length(&self, length: usize) -> usize {
+        return length;
+    }
+
+    fn encode_to(&self, input: &[u8], output: &mut String) {
+        static TABLE: [char; 16] = [
+            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
+        ];
+        let mut column: usize = 0;
+        let mut x: usize = 0;
+        while x < input.len() {
+            let c: u8 = input[x];
+            if c == b'\n' || (c == b'\r' && input.get(x + 1) == Some(&b'\n')) {
+                output.push_str("\r\n");
+                column = 0;
+                x += if c == b'\r' { 2 } else { 1 };
+                continue;
+            }
+            /* 空格和制表符在行尾时要编码. */
+            let end_of_line: bool = match input.get(x + 1) {
+                None | Some(b'\n') => true,
+                Some(b'\r') => input.get(x + 2) == Some(&b'\n'),
+                _ => false,
+            };
+            let literal: bool = match c {
+                b' ' | b'\t' => !end_of_line,
+                b'=' => false,
+                33..=126 => true,
+                _ => false,
+            };
+            let length: usize = if literal { 1 } else { 3 };
+            /* 加上软换行的'='不超过76个字符, 最后一个字符不需要软换行. */
+            let limit: usize = if end_of_line { 76 } else { 75 };
+            if limit < column + length {
+                output.push_str("=\r\n");
+                column = 0;
+            }
+            if literal {
+                output.push(c as char);
+            } else {
+                output.push('=');
+                output.push(TABLE[(c >> 4) as usize]);
+                output.push(TABLE[(c & 0x0F) as usize]);
             }
-            let y: u8 = input as u8 & 0b1;
-            input >>= 1;
-            output.push((y + b'0') as char);
+            column += length;
+            x += 1;
         }
-        output.push('b');
-        output.push('0');
-        return output.chars().rev().collect();
+        return;
     }
 
-    pub fn u32_to_bin(input: u32) -> String {
-        let mut input: u32 = input;
-        let mut output: String = String::new();
-        for _x in 0..32 {
-            let y: u8 = input as u8 & 0b1;
-            input >>= 1;
-            output.push((y + b'0') as char);
+    fn decode_to(&self, input: &str, output: &mut Vec<u8>) -> Result<(), String> {
+        output.extend_from_slice(&QuotedPrintableEncoder::decode(input)?);
+        return Ok(());
+    }
+}
+
+/// RFC 2047的encoded-word, 比如'=?UTF-8?B?5L2g5aW9?=', 用于邮件的协议头.
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::EncodedWordEncoder;
+///
+/// assert_eq!(EncodedWordEncoder::encode_b("你好"), "=?UTF-8?B?5L2g5aW9?=");
+/// assert_eq!(EncodedWordEncoder::encode_q("你好 hi"), "=?UTF-8?Q?=E4=BD=A0=E5=A5=BD_hi?=");
+/// assert_eq!(EncodedWordEncoder::decode("Re: =?UTF-8?B?5L2g5aW9?=").unwrap(), "Re: 你好");
+/// ```
+#[derive(Debug, Clone)]
+pub struct EncodedWordEncoder;
+
+impl EncodedWordEncoder {
+    /// 支持解码的字符集, 大写.
+    const CHARSETS: [&str; 10] = [
+        "UTF-8",
+        "UTF8",
+        "US-ASCII",
+        "ASCII",
+        "ISO-8859-1",
+        "LATIN1",
+        "GB2312",
+        "GBK",
+        "GB18030",
+        "X-GBK",
+    ];
+
+    /// 用Base64编码, 每个encoded-word不超过75个字符, 超过的拆成多个, 以'\r\n '分隔.
+    pub fn encode_b(input: &str) -> String {
+        /* '=?UTF-8?B?'和'?='占12个字符, 剩下63个字符最多放45个字节. */
+        let mut words: Vec<String> = Vec::new();
+        for chunk in Self::split(input, |x| x.len() <= 45) {
+            words.push(format!(
+                "=?UTF-8?B?{}?=",
+                Base64Encoder::encode(chunk.as_bytes())
+            ));
         }
-        return output.chars().rev().collect();
+        return words.join("\r\n ");
     }
 
-    pub fn u32_to_bin_(input: u32) -> String {
-        let mut input: u32 = input;
+    /// 用Q编码, 空格编码成'_', 每个encoded-word不超过75个字符, 超过的拆成多个, 以'\r\n '分隔.
+    pub fn encode_q(input: &str) -> String {
+        let mut words: Vec<String> = Vec::new();
+        for chunk in Self::split(input, |x| Self::q_encode(x).len() <= 63) {
+            words.push(format!("=?UTF-8?Q?{}?=", Self::q_encode(chunk)));
+        }
+        return words.join("\r\n ");
+    }
+
+    /// 解码包含encoded-word的文本, 相邻的encoded-word之间的空白会被去掉.
+    ///
+    /// 支持的字符集, UTF-8, US-ASCII, ISO-8859-1, GB2312, GBK, GB18030, 不区分大小写.
+    /// 不是encoded-word的部分保持不变, 不支持的字符集或者编码方式也当作普通文本, 保持不变, 见RFC 2047 6.3.
+    ///
+    /// - @exception 错误的格式.
+    /// - @exception 出现未预期的字符.
+    /// - @exception 内容不是UTF-8编码.
+    pub fn decode(input: &str) -> Result<String, String> {
         let mut output: String = String::new();
-        for x in 0..32 {
-            if x != 0 && x % 8 == 0 {
-                output.push('_');
+        /* 连续的encoded-word先拼起来再转字符串, 因为一个字符可能被拆到两个encoded-word. */
+        let mut pending: Vec<u8> = Vec::new();
+        let mut pending_charset: String = String::new();
+        /* 上一个encoded-word之后的空白, 如果后面还是encoded-word, 就丢掉. */
+        let mut whitespace: &str = "";
+        let mut rest: &str = input;
+        while rest.len() != 0 {
+            if let Some((charset, data, length)) = Self::parse_word(rest)? {
+                if charset != pending_charset && pending.len() != 0 {
+                    output.push_str(&Self::to_string(&pending, &pending_charset)?);
+                    pending.clear();
+                }
+                pending_charset = charset;
+                pending.extend_from_slice(&data);
+                rest = &rest[length..];
+                /* 记住后面的空白. */
+                let length: usize = rest.len() - rest.trim_start().len();
+                whitespace = &rest[..length];
+                rest = &rest[length..];
+                continue;
             }
-            let y: u8 = input as u8 & 0b1;
-            input >>= 1;
-            output.push((y + b'0') as char);
+            if pending.len() != 0 {
+                output.push_str(&Self::to_string(&pending, &pending_charset)?);
+                pending.clear();
+            }
+            output.push_str(whitespace);
+            whitespace = "";
+            let c: char = rest.chars().next().expect("NEVER");
+            output.push(c);
+            rest = &rest[c.len_utf8()..];
         }
-        output.push('b');
-        output.push('0');
-        return output.chars().rev().collect();
+        if pending.len() != 0 {
+            output.push_str(&Self::to_string(&pending, &pending_charset)?);
+        }
+        output.push_str(whitespace);
+        return Ok(output);
     }
 
-    pub fn u16_to_bin(input: u16) -> String {
-        let mut input: u16 = input;
-        let mut output: String = String::new();
-        for _x in 0..16 {
-            let y: u8 = input as u8 & 0b1;
-            input >>= 1;
-            output.push((y + b'0') as char);
+    /// 按字符拆分, 每段满足f.
+    fn split<F>(input: &str, f: F) -> Vec<&str>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let mut output: Vec<&str> = Vec::new();
+        let mut start: usize = 0;
+        let mut end: usize = 0;
+        for (x, c) in input.char_indices() {
+            if !f(&input[start..x + c.len_utf8()]) && start < end {
+                output.push(&input[start..end]);
+                start = end;
+            }
+            end = x + c.len_utf8();
         }
-        return output.chars().rev().collect();
+        if start < input.len() || output.len() == 0 {
+            output.push(&input[start..]);
+        }
+        return output;
     }
 
-    pub fn u16_to_bin_(input: u16) -> String {
-        let mut input: u16 = input;
+    fn q_encode(input: &str) -> String {
+        static TABLE: [char; 16] = [
+            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
+        ];
         let mut output: String = String::new();
-        for x in 0..16 {
-            if x != 0 && x % 8 == 0 {
+        for x in input.as_bytes() {
+            if *x == b' ' {
                 output.push('_');
+            } else if x.is_ascii_alphanumeric() || "!*+-/".contains(*x as char) {
+                output.push(*x as char);
+            } else {
+                output.push('=');
+                output.push(TABLE[(*x >> 4) as usize]);
+                output.push(TABLE[(*x & 0x0F) as usize]);
             }
-            let y: u8 = input as u8 & 0b1;
-            input >>= 1;
-            output.push((y + b'0') as char);
         }
-        output.push('b');
-        output.push('0');
-        return output.chars().rev().collect();
+        return output;
     }
 
-    pub fn u8_to_bin(input: u8) -> String {
-        let mut input: u8 = input;
-        let mut output: String = String::new();
-        for _x in 0..8 {
-            let y: u8 = input as u8 & 0b1;
-            input >>= 1;
-            output.push((y + b'0') as char);
+    /// - @exception 错误的格式.
+    fn q_decode(input: &str) -> Result<Vec<u8>, String> {
+        let input: &[u8] = input.as_bytes();
+        let mut output: Vec<u8> = Vec::with_capacity(input.len());
+        let mut x: usize = 0;
+        while x < input.len() {
+            match input[x] {
+                b'_' => output.push(b' '),
+                b'=' => {
+                    let high: Option<u32> =
+                        input.get(x + 1).and_then(|c| (*c as char).to_digit(16));
+                    let low: Option<u32> = input.get(x + 2).and_then(|c| (*c as char).to_digit(16));
+                    match (high, low) {
+                        (Some(high), Some(low)) => output.push((high << 4 | low) as u8),
+                        _ => return Err(iceyee_error::c!("错误的格式")),
+                    }
+                    x += 2;
+                }
+                c => output.push(c),
+            }
+            x += 1;
         }
-        return output.chars().rev().collect();
+        return Ok(output);
     }
 
-    pub fn u8_to_bin_(input: u8) -> String {
-        let mut input: u8 = input;
-        let mut output: String = String::new();
-        for x in 0..8 {
-            if x != 0 && x % 8 == 0 {
-                output.push('_');
+    /// 如果开头是encoded-word, 返回字符集, 解码后的数据, 以及encoded-word的长度.
+    ///
+    /// 不支持的字符集或者编码方式返回[None].
+    fn parse_word(input: &str) -> Result<Option<(String, Vec<u8>, usize)>, String> {
+        if !input.starts_with("=?") {
+            return Ok(None);
+        }
+        let mut parts = input[2..].splitn(3, '?');
+        let (charset, encoding, rest): (&str, &str, &str) =
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(charset), Some(encoding), Some(rest)) => (charset, encoding, rest),
+                _ => return Ok(None),
+            };
+        let text: &str = match rest.find("?=") {
+            Some(x) => &rest[..x],
+            None => return Ok(None),
+        };
+        if charset.len() == 0 || text.contains(char::is_whitespace) {
+            return Ok(None);
+        }
+        let length: usize = 2 + charset.len() + 1 + encoding.len() + 1 + text.len() + 2;
+        /* 'UTF-8*zh'的'*'后面是语言. */
+        let charset: String = charset
+            .split('*')
+            .next()
+            .expect("NEVER")
+            .to_ascii_uppercase();
+        if !Self::CHARSETS.contains(&charset.as_str()) {
+            return Ok(None);
+        }
+        let data: Vec<u8> = match encoding {
+            "B" | "b" => Base64Encoder::decode_with(text, &Base64Config::new().set_lenient(true))?,
+            "Q" | "q" => Self::q_decode(text)?,
+            _ => return Ok(None),
+        };
+        return Ok(Some((charset, data, length)));
+    }
+
+    fn to_string(data: &[u8], charset: &str) -> Result<String, String> {
+        return match charset {
+            "UTF-8" | "UTF8" | "US-ASCII" | "ASCII" => {
+                String::from_utf8(data.to_vec()).map_err(|_| iceyee_error::c!("内容不是UTF-8编码"))
             }
-            let y: u8 = input as u8 & 0b1;
-            input >>= 1;
-            output.push((y + b'0') as char);
+            "ISO-8859-1" | "LATIN1" => Ok(data.iter().map(|x| *x as char).collect()),
+            "GB2312" | "GBK" | "GB18030" | "X-GBK" => {
+                Gb18030Encoder::decode(data).map_err(|e| iceyee_error::b!(e, charset))
+            }
+            _ => Err(iceyee_error::c!("不支持的字符集", charset)),
+        };
+    }
+}
+
+/// Punycode编码, RFC 3492, 以及IDNA的ToASCII和ToUnicode, 用于国际化域名.
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::PunycodeEncoder;
+///
+/// assert_eq!(PunycodeEncoder::encode("bücher").unwrap(), "bcher-kva");
+/// assert_eq!(PunycodeEncoder::to_ascii("例子.中国").unwrap(), "xn--fsqu00a.xn--fiqs8s");
+/// assert_eq!(PunycodeEncoder::to_unicode("xn--fsqu00a.xn--fiqs8s").unwrap(), "例子.中国");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PunycodeEncoder;
+
+impl PunycodeEncoder {
+    const BASE: u32 = 36;
+    const TMIN: u32 = 1;
+    const TMAX: u32 = 26;
+    const SKEW: u32 = 38;
+    const DAMP: u32 = 700;
+    const INITIAL_BIAS: u32 = 72;
+    const INITIAL_N: u32 = 128;
+
+    /// 编码一个标签, 不包含'xn--'.
+    ///
+    /// - @exception 数值溢出.
+    pub fn encode(input: &str) -> Result<String, String> {
+        let input: Vec<u32> = input.chars().map(|c| c as u32).collect();
+        let mut output: String = input
+            .iter()
+            .filter(|c| **c < 0x80)
+            .map(|c| char::from_u32(*c).expect("NEVER"))
+            .collect();
+        let basic: usize = output.len();
+        let mut handled: usize = basic;
+        if basic != 0 {
+            output.push('-');
         }
-        output.push('b');
-        output.push('0');
-        return output.chars().rev().collect();
+        let mut n: u32 = Self::INITIAL_N;
+        let mut delta: u32 = 0;
+        let mut bias: u32 = Self::INITIAL_BIAS;
+        while handled < input.len() {
+            let m: u32 = *input.iter().filter(|c| n <= **c).min().expect("NEVER");
+            delta = (m - n)
+                .checked_mul(handled as u32 + 1)
+                .and_then(|x| x.checked_add(delta))
+                .ok_or_else(|| iceyee_error::c!("数值溢出"))?;
+            n = m;
+            for c in input.iter() {
+                if *c < n {
+                    delta = delta
+                        .checked_add(1)
+                        .ok_or_else(|| iceyee_error::c!("数值溢出"))?;
+                }
+                if *c == n {
+                    let mut q: u32 = delta;
+                    let mut k: u32 = Self::BASE;
+                    loop {
+                        let t: u32 = Self::threshold(k, bias);
+                        if q < t {
+                            break;
+                        }
+                        output.push(Self::digit(t + (q - t) % (Self::BASE - t)));
+                        q = (q - t) / (Self::BASE - t);
+                        k += Self::BASE;
+                    }
+                    output.push(Self::digit(q));
+                    bias = Self::adapt(delta, handled as u32 + 1, handled == basic);
+                    delta = 0;
+                    handled += 1;
+                }
+            }
+            delta += 1;
+            n += 1;
+        }
+        return Ok(output);
     }
 
-    pub fn u64_to_oct(input: u64) -> String {
-        let mut input: u64 = input;
-        let mut output: String = String::new();
-        for _x in 0..22 {
-            let y: u8 = input as u8 & 0b111;
-            input >>= 3;
-            output.push((y + b'0') as char);
+    /// 解码一个标签, 不包含'xn--'.
+    ///
+    /// - @exception 出现未预期的字符.
+    /// - @exception 数值溢出.
+    /// - @exception 无效的码点.
+    pub fn decode(input: &str) -> Result<String, String> {
+        let (basic, extended): (&str, &str) = match input.rfind('-') {
+            Some(x) => (&input[..x], &input[x + 1..]),
+            None => ("", input),
+        };
+        if !basic.is_ascii() {
+            return Err(iceyee_error::c!("出现未预期的字符"));
         }
-        return output.chars().rev().collect();
+        let mut output: Vec<char> = basic.chars().collect();
+        let mut n: u32 = Self::INITIAL_N;
+        let mut i: u32 = 0;
+        let mut bias: u32 = Self::INITIAL_BIAS;
+        let mut extended = extended.bytes().peekable();
+        while extended.peek().is_some() {
+            let old_i: u32 = i;
+            let mut w: u32 = 1;
+            let mut k: u32 = Self::BASE;
+            loop {
+                let digit: u32 = match extended.next() {
+                    Some(c @ b'a'..=b'z') => (c - b'a') as u32,
+                    Some(c @ b'A'..=b'Z') => (c - b'A') as u32,
+                    Some(c @ b'0'..=b'9') => (c - b'0') as u32 + 26,
+                    _ => return Err(iceyee_error::c!("出现未预期的字符")),
+                };
+                i = digit
+                    .checked_mul(w)
+                    .and_then(|x| x.checked_add(i))
+                    .ok_or_else(|| iceyee_error::c!("数值溢出"))?;
+                let t: u32 = Self::threshold(k, bias);
+                if digit < t {
+                    break;
+                }
+                w = w
+                    .checked_mul(Self::BASE - t)
+                    .ok_or_else(|| iceyee_error::c!("数值溢出"))?;
+                k += Self::BASE;
+            }
+            let length: u32 = output.len() as u32 + 1;
+            bias = Self::adapt(i - old_i, length, old_i == 0);
+            n = n
+                .checked_add(i / length)
+                .ok_or_else(|| iceyee_error::c!("数值溢出"))?;
+            i %= length;
+            let c: char = char::from_u32(n).ok_or_else(|| iceyee_error::c!("无效的码点", n))?;
+            output.insert(i as usize, c);
+            i += 1;
+        }
+        return Ok(output.into_iter().collect());
     }
 
-    pub fn u64_to_oct_(input: u64) -> String {
-        let mut input: u64 = input;
-        let mut output: String = String::new();
-        for x in 0..22 {
-            if x != 0 && false {
-                output.push('_');
+    /// IDNA ToASCII, 把域名转成ASCII, 非ASCII的标签编码成'xn--'开头.
+    ///
+    /// 转成小写, 并且把'。', '．', '｡'当作'.', 没有实现UTS #46完整的映射.
+    ///
+    /// - @exception 无效的域名, 空的标签, 标签超过63个字符, 或者域名超过253个字符.
+    /// - @exception 数值溢出.
+    pub fn to_ascii(host: &str) -> Result<String, String> {
+        let host: String = host
+            .replace(['\u{3002}', '\u{FF0E}', '\u{FF61}'], ".")
+            .to_lowercase();
+        let mut labels: Vec<String> = Vec::new();
+        for (x, label) in host.split('.').enumerate() {
+            /* 最后一个'.'表示根域名, 允许为空. */
+            if label.len() == 0 && !(x != 0 && x == host.split('.').count() - 1) {
+                return Err(iceyee_error::c!("无效的域名", host));
             }
-            let y: u8 = input as u8 & 0b111;
-            input >>= 3;
-            output.push((y + b'0') as char);
+            let label: String = if label.is_ascii() {
+                label.to_string()
+            } else {
+                format!("xn--{}", Self::encode(label)?)
+            };
+            if 63 < label.len() {
+                return Err(iceyee_error::c!("无效的域名", host));
+            }
+            labels.push(label);
         }
-        output.push('o');
-        output.push('0');
-        return output.chars().rev().collect();
+        let output: String = labels.join(".");
+        if 253 < output.trim_end_matches('.').len() {
+            return Err(iceyee_error::c!("无效的域名", host));
+        }
+        return Ok(output);
     }
 
-    pub fn u32_to_oct(input: u32) -> String {
-        let mut input: u32 = input;
-        let mut output: String = String::new();
-        for _x in 0..11 {
-            let y: u8 = input as u8 & 0b111;
-            input >>= 3;
-            output.push((y + b'0') as char);
+    /// IDNA ToUnicode, 把'xn--'开头的标签解码, 其它标签转成小写.
+    ///
+    /// - @exception 出现未预期的字符.
+    /// - @exception 数值溢出.
+    /// - @exception 无效的码点.
+    pub fn to_unicode(host: &str) -> Result<String, String> {
+        let mut labels: Vec<String> = Vec::new();
+        for label in host.split('.') {
+            let label: String = label.to_lowercase();
+            match label.strip_prefix("xn--") {
+                Some(label) => labels.push(Self::decode(label)?),
+                None => labels.push(label),
+            }
         }
-        return output.chars().rev().collect();
+        return Ok(labels.join("."));
     }
 
-    pub fn u32_to_oct_(input: u32) -> String {
-        let mut input: u32 = input;
-        let mut output: String = String::new();
-        for x in 0..11 {
-            if x != 0 && false {
-                output.push('_');
+    fn threshold(k: u32, bias: u32) -> u32 {
+        if k <= bias {
+            return Self::TMIN;
+        } else if bias + Self::TMAX <= k {
+            return Self::TMAX;
+        } else {
+            return k - bias;
+        }
+    }
+
+    fn digit(x: u32) -> char {
+        if x < 26 {
+            return (b'a' + x as u8) as char;
+        } else {
+            return (b'0' + (x - 26) as u8) as char;
+        }
+    }
+
+    fn adapt(delta: u32, length: u32, first: bool) -> u32 {
+        let mut delta: u32 = if first { delta / Self::DAMP } else { delta / 2 };
+        delta += delta / length;
+        let mut k: u32 = 0;
+        while ((Self::BASE - Self::TMIN) * Self::TMAX) / 2 < delta {
+            delta /= Self::BASE - Self::TMIN;
+            k += Self::BASE;
+        }
+        return k + (Self::BASE - Self::TMIN + 1) * delta / (delta + Self::SKEW);
+    }
+}
+
+/// GB18030编码, 兼容GBK和GB2312, 可以表示所有的Unicode字符.
+///
+/// 单字节是ASCII, 双字节查表, 四字节按区间线性映射, 映射与GB18030-2005一致.
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::Gb18030Encoder;
+///
+/// assert_eq!(Gb18030Encoder::encode("你好a"), vec![0xC4, 0xE3, 0xBA, 0xC3, b'a']);
+/// assert_eq!(Gb18030Encoder::encode("€"), vec![0xA2, 0xE3]);
+/// assert_eq!(Gb18030Encoder::encode("😀"), vec![0x94, 0x39, 0xFC, 0x36]);
+/// assert_eq!(Gb18030Encoder::decode(&[0xC4, 0xE3, 0xBA, 0xC3]).unwrap(), "你好");
+/// assert_eq!(Gb18030Encoder::decode_lossy(&[b'a', 0xFF, 0xC4]), "a\u{FFFD}\u{FFFD}");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Gb18030Encoder;
+
+impl Gb18030Encoder {
+    /// 双字节的映射表, 第一个字节0x81-0xFE, 第二个字节0x40-0x7E和0x80-0xFE, 共126*190项,
+    /// 每项是大端的u16码点.
+    const TABLE: &[u8; 47880] = include_bytes!("gb18030.bin");
+
+    /// 四字节映射到BMP的区间, (四字节的序号, 码点), 区间内两者同时递增.
+    ///
+    /// 四字节的序号是(b1-0x81)*12600+(b2-0x30)*1260+(b3-0x81)*10+(b4-0x30).
+    const RANGES: [(u32, u32); 206] = [
+        (0, 0x0080),
+        (36, 0x00A5),
+        (38, 0x00A9),
+        (45, 0x00B2),
+        (50, 0x00B8),
+        (81, 0x00D8),
+        (89, 0x00E2),
+        (95, 0x00EB),
+        (96, 0x00EE),
+        (100, 0x00F4),
+        (103, 0x00F8),
+        (104, 0x00FB),
+        (105, 0x00FD),
+        (109, 0x0102),
+        (126, 0x0114),
+        (133, 0x011C),
+        (148, 0x012C),
+        (172, 0x0145),
+        (175, 0x0149),
+        (179, 0x014E),
+        (208, 0x016C),
+        (306, 0x01CF),
+        (307, 0x01D1),
+        (308, 0x01D3),
+        (309, 0x01D5),
+        (310, 0x01D7),
+        (311, 0x01D9),
+        (312, 0x01DB),
+        (313, 0x01DD),
+        (341, 0x01FA),
+        (428, 0x0252),
+        (443, 0x0262),
+        (544, 0x02C8),
+        (545, 0x02CC),
+        (558, 0x02DA),
+        (741, 0x03A2),
+        (742, 0x03AA),
+        (749, 0x03C2),
+        (750, 0x03CA),
+        (805, 0x0402),
+        (819, 0x0450),
+        (820, 0x0452),
+        (7922, 0x2011),
+        (7924, 0x2017),
+        (7925, 0x201A),
+        (7927, 0x201E),
+        (7934, 0x2027),
+        (7943, 0x2031),
+        (7944, 0x2034),
+        (7945, 0x2036),
+        (7950, 0x203C),
+        (8062, 0x20AD),
+        (8148, 0x2104),
+        (8149, 0x2106),
+        (8152, 0x210A),
+        (8164, 0x2117),
+        (8174, 0x2122),
+        (8236, 0x216C),
+        (8240, 0x217A),
+        (8262, 0x2194),
+        (8264, 0x219A),
+        (8374, 0x2209),
+        (8380, 0x2210),
+        (8381, 0x2212),
+        (8384, 0x2216),
+        (8388, 0x221B),
+        (8390, 0x2221),
+        (8392, 0x2224),
+        (8393, 0x2226),
+        (8394, 0x222C),
+        (8396, 0x222F),
+        (8401, 0x2238),
+        (8406, 0x223E),
+        (8416, 0x2249),
+        (8419, 0x224D),
+        (8424, 0x2253),
+        (8437, 0x2262),
+        (8439, 0x2268),
+        (8445, 0x2270),
+        (8482, 0x2296),
+        (8485, 0x229A),
+        (8496, 0x22A6),
+        (8521, 0x22C0),
+        (8603, 0x2313),
+        (8936, 0x246A),
+        (8946, 0x249C),
+        (9046, 0x254C),
+        (9050, 0x2574),
+        (9063, 0x2590),
+        (9066, 0x2596),
+        (9076, 0x25A2),
+        (9092, 0x25B4),
+        (9100, 0x25BE),
+        (9108, 0x25C8),
+        (9111, 0x25CC),
+        (9113, 0x25D0),
+        (9131, 0x25E6),
+        (9162, 0x2607),
+        (9164, 0x260A),
+        (9218, 0x2641),
+        (9219, 0x2643),
+        (11329, 0x2E82),
+        (11331, 0x2E85),
+        (11334, 0x2E89),
+        (11336, 0x2E8D),
+        (11346, 0x2E98),
+        (11361, 0x2EA8),
+        (11363, 0x2EAB),
+        (11366, 0x2EAF),
+        (11370, 0x2EB4),
+        (11372, 0x2EB8),
+        (11375, 0x2EBC),
+        (11389, 0x2ECB),
+        (11682, 0x2FFC),
+        (11686, 0x3004),
+        (11687, 0x3018),
+        (11692, 0x301F),
+        (11694, 0x302A),
+        (11714, 0x303F),
+        (11716, 0x3094),
+        (11723, 0x309F),
+        (11725, 0x30F7),
+        (11730, 0x30FF),
+        (11736, 0x312A),
+        (11982, 0x322A),
+        (11989, 0x3232),
+        (12102, 0x32A4),
+        (12336, 0x3390),
+        (12348, 0x339F),
+        (12350, 0x33A2),
+        (12384, 0x33C5),
+        (12393, 0x33CF),
+        (12395, 0x33D3),
+        (12397, 0x33D6),
+        (12510, 0x3448),
+        (12553, 0x3474),
+        (12851, 0x359F),
+        (12962, 0x360F),
+        (12973, 0x361B),
+        (13738, 0x3919),
+        (13823, 0x396F),
+        (13919, 0x39D1),
+        (13933, 0x39E0),
+        (14080, 0x3A74),
+        (14298, 0x3B4F),
+        (14585, 0x3C6F),
+        (14698, 0x3CE1),
+        (15583, 0x4057),
+        (15847, 0x4160),
+        (16318, 0x4338),
+        (16434, 0x43AD),
+        (16438, 0x43B2),
+        (16481, 0x43DE),
+        (16729, 0x44D7),
+        (17102, 0x464D),
+        (17122, 0x4662),
+        (17315, 0x4724),
+        (17320, 0x472A),
+        (17402, 0x477D),
+        (17418, 0x478E),
+        (17859, 0x4948),
+        (17909, 0x497B),
+        (17911, 0x497E),
+        (17915, 0x4984),
+        (17916, 0x4987),
+        (17936, 0x499C),
+        (17939, 0x49A0),
+        (17961, 0x49B8),
+        (18664, 0x4C78),
+        (18703, 0x4CA4),
+        (18814, 0x4D1A),
+        (18962, 0x4DAF),
+        (19043, 0x9FA6),
+        (33469, 0xE76C),
+        (33470, 0xE7C8),
+        (33471, 0xE7E7),
+        (33484, 0xE815),
+        (33485, 0xE819),
+        (33490, 0xE81F),
+        (33497, 0xE827),
+        (33501, 0xE82D),
+        (33505, 0xE833),
+        (33513, 0xE83C),
+        (33520, 0xE844),
+        (33536, 0xE856),
+        (33550, 0xE865),
+        (37845, 0xF92D),
+        (37921, 0xF97A),
+        (37948, 0xF996),
+        (38029, 0xF9E8),
+        (38038, 0xF9F2),
+        (38064, 0xFA10),
+        (38065, 0xFA12),
+        (38066, 0xFA15),
+        (38069, 0xFA19),
+        (38075, 0xFA22),
+        (38076, 0xFA25),
+        (38078, 0xFA2A),
+        (39108, 0xFE32),
+        (39109, 0xFE45),
+        (39113, 0xFE53),
+        (39114, 0xFE58),
+        (39115, 0xFE67),
+        (39116, 0xFE6C),
+        (39265, 0xFF5F),
+        (39394, 0xFFE6),
+    ];
+
+    /// BMP的四字节的最大序号, 0x8431A439.
+    const BMP_MAX: u32 = 39419;
+
+    /// U+10000的四字节的序号, 0x90308130.
+    const SUPPLEMENTARY: u32 = 189000;
+
+    /// 编码.
+    pub fn encode(input: &str) -> Vec<u8> {
+        let reverse: &Vec<u16> = Self::reverse();
+        let mut output: Vec<u8> = Vec::with_capacity(input.len());
+        for c in input.chars() {
+            let c: u32 = c as u32;
+            if c < 0x80 {
+                output.push(c as u8);
+                continue;
             }
-            let y: u8 = input as u8 & 0b111;
-            input >>= 3;
-            output.push((y + b'0') as char);
+            if c <= 0xFFFF && reverse[c as usize] != 0 {
+                let index: u32 = reverse[c as usize] as u32 - 1;
+                let trail: u32 = index % 190;
+                output.push((index / 190 + 0x81) as u8);
+                output.push((trail + if trail < 0x3F { 0x40 } else { 0x41 }) as u8);
+                continue;
+            }
+            let mut pointer: u32 = if c <= 0xFFFF {
+                let x: usize = Self::RANGES.partition_point(|x| x.1 <= c) - 1;
+                Self::RANGES[x].0 + (c - Self::RANGES[x].1)
+            } else {
+                Self::SUPPLEMENTARY + (c - 0x10000)
+            };
+            let b4: u32 = pointer % 10;
+            pointer /= 10;
+            let b3: u32 = pointer % 126;
+            pointer /= 126;
+            let b2: u32 = pointer % 10;
+            pointer /= 10;
+            output.push((pointer + 0x81) as u8);
+            output.push((b2 + 0x30) as u8);
+            output.push((b3 + 0x81) as u8);
+            output.push((b4 + 0x30) as u8);
         }
-        output.push('o');
-        output.push('0');
-        return output.chars().rev().collect();
+        return output;
     }
 
-    pub fn u16_to_oct(input: u16) -> String {
-        let mut input: u16 = input;
-        let mut output: String = String::new();
-        for _x in 0..6 {
-            let y: u8 = input as u8 & 0b111;
-            input >>= 3;
-            output.push((y + b'0') as char);
+    /// 解码, GB18030, GBK或者GB2312.
+    ///
+    /// - @exception 无效的长度, 最后一个字符不完整.
+    /// - @exception 出现未预期的字符.
+    pub fn decode(input: &[u8]) -> Result<String, String> {
+        let mut output: String = String::with_capacity(input.len() * 3 / 2);
+        let mut x: usize = 0;
+        while x < input.len() {
+            let (c, length): (char, usize) = Self::decode_char(&input[x..])?;
+            output.push(c);
+            x += length;
         }
-        return output.chars().rev().collect();
+        return Ok(output);
     }
 
-    pub fn u16_to_oct_(input: u16) -> String {
-        let mut input: u16 = input;
-        let mut output: String = String::new();
-        for x in 0..6 {
-            if x != 0 && false {
-                output.push('_');
+    /// 解码, 无效的字节替换成U+FFFD.
+    pub fn decode_lossy(input: &[u8]) -> String {
+        let mut output: String = String::with_capacity(input.len() * 3 / 2);
+        let mut x: usize = 0;
+        while x < input.len() {
+            match Self::decode_char(&input[x..]) {
+                Ok((c, length)) => {
+                    output.push(c);
+                    x += length;
+                }
+                Err(_) => {
+                    /* 只跳过第一个字节, 后面的字节可能是ASCII. */
+                    output.push('\u{FFFD}');
+                    x += 1;
+                }
+            }
+        }
+        return output;
+    }
+
+    /// 解码开头的一个字符, 返回字符和占用的字节数.
+    ///
+    /// - @exception 无效的长度.
+    /// - @exception 出现未预期的字符.
+    fn decode_char(input: &[u8]) -> Result<(char, usize), String> {
+        let b1: u8 = input[0];
+        if b1 < 0x80 {
+            return Ok((b1 as char, 1));
+        }
+        if b1 == 0x80 || b1 == 0xFF {
+            return Err(iceyee_error::c!("出现未预期的字符"));
+        }
+        let b2: u8 = *input.get(1).ok_or_else(|| iceyee_error::c!("无效的长度"))?;
+        match b2 {
+            0x40..=0x7E | 0x80..=0xFE => {
+                let index: usize = (b1 - 0x81) as usize * 190
+                    + (b2 - if b2 < 0x80 { 0x40 } else { 0x41 }) as usize;
+                let c: u16 =
+                    u16::from_be_bytes([Self::TABLE[index * 2], Self::TABLE[index * 2 + 1]]);
+                let c: char = char::from_u32(c as u32).expect("NEVER");
+                return Ok((c, 2));
+            }
+            0x30..=0x39 => {}
+            _ => return Err(iceyee_error::c!("出现未预期的字符")),
+        }
+        if input.len() < 4 {
+            return Err(iceyee_error::c!("无效的长度"));
+        }
+        let b3: u8 = input[2];
+        let b4: u8 = input[3];
+        if !(0x81..=0xFE).contains(&b3) || !(0x30..=0x39).contains(&b4) {
+            return Err(iceyee_error::c!("出现未预期的字符"));
+        }
+        let pointer: u32 = (b1 - 0x81) as u32 * 12600
+            + (b2 - 0x30) as u32 * 1260
+            + (b3 - 0x81) as u32 * 10
+            + (b4 - 0x30) as u32;
+        let c: u32 = if pointer <= Self::BMP_MAX {
+            let x: usize = Self::RANGES.partition_point(|x| x.0 <= pointer) - 1;
+            Self::RANGES[x].1 + (pointer - Self::RANGES[x].0)
+        } else if Self::SUPPLEMENTARY <= pointer && pointer - Self::SUPPLEMENTARY <= 0xFFFFF {
+            0x10000 + (pointer - Self::SUPPLEMENTARY)
+        } else {
+            return Err(iceyee_error::c!("出现未预期的字符"));
+        };
+        let c: char = char::from_u32(c).ok_or_else(|| iceyee_error::c!("出现未预期的字符"))?;
+        return Ok((c, 4));
+    }
+
+    /// BMP码点到双字节的序号+1, 0表示没有双字节.
+    fn reverse() -> &'static Vec<u16> {
+        static REVERSE: OnceLock<Vec<u16>> = OnceLock::new();
+        return REVERSE.get_or_init(|| {
+            let mut reverse: Vec<u16> = vec![0; 0x10000];
+            for index in 0..Self::TABLE.len() / 2 {
+                let c: u16 =
+                    u16::from_be_bytes([Self::TABLE[index * 2], Self::TABLE[index * 2 + 1]]);
+                if c != 0 && reverse[c as usize] == 0 {
+                    reverse[c as usize] = index as u16 + 1;
+                }
+            }
+            return reverse;
+        });
+    }
+}
+
+/// 流式编码的状态.
+///
+/// - pending, 未处理的输入, 不足一组的部分.
+/// - output, 已处理的输出, position之前的已经交出去.
+/// - column, Base64当前行的字符数, 用于换行.
+/// - padded, Base64解码已经遇到'=', 后面不能再有数据.
+#[derive(Debug, Clone)]
+struct CodecState {
+    codec: Codec,
+    pending: Vec<u8>,
+    output: Vec<u8>,
+    position: usize,
+    column: usize,
+    padded: bool,
+}
+
+impl CodecState {
+    fn new(codec: Codec) -> Self {
+        return CodecState {
+            codec: codec,
+            pending: Vec::new(),
+            output: Vec::new(),
+            position: 0,
+            column: 0,
+            padded: false,
+        };
+    }
+
+    /// 处理pending里完整的组, 剩下的留在pending.
+    fn update(&mut self) -> Result<(), String> {
+        match self.codec {
+            Codec::Base64Encode(config) => {
+                let length: usize = self.pending.len() / 3 * 3;
+                let text: String =
+                    Base64Encoder::encode_with(&self.pending[..length], &config.set_line_width(0));
+                self.pending.drain(..length);
+                self.wrap(text.as_bytes(), config.line_width());
+            }
+            Codec::Base64Decode(config) => {
+                /* 找到第4n个有效字符之后的位置, 空白字符不算. */
+                let mut count: usize = 0;
+                let mut length: usize = 0;
+                for (x, c) in self.pending.iter().enumerate() {
+                    let skip: bool = match c {
+                        b' ' | b'\t' => config.lenient(),
+                        b'\r' | b'\n' => config.lenient() || config.line_width() != 0,
+                        _ => false,
+                    };
+                    if !skip {
+                        count += 1;
+                        if count % 4 == 0 {
+                            length = x + 1;
+                        }
+                    }
+                }
+                if length == 0 {
+                    return Ok(());
+                }
+                self.decode_base64(length, &config)?;
+            }
+            Codec::HexEncode => {
+                let text: String = HexEncoder::encode(&self.pending);
+                self.pending.clear();
+                self.output.extend_from_slice(text.as_bytes());
+            }
+            Codec::HexDecode => {
+                let length: usize = self.pending.len() / 2 * 2;
+                let text: &str = std::str::from_utf8(&self.pending[..length])
+                    .map_err(|_| iceyee_error::c!("出现未预期的字符"))?;
+                let data: Vec<u8> = HexEncoder::decode(text)?;
+                self.pending.drain(..length);
+                self.output.extend_from_slice(&data);
             }
-            let y: u8 = input as u8 & 0b111;
-            input >>= 3;
-            output.push((y + b'0') as char);
         }
-        output.push('o');
-        output.push('0');
-        return output.chars().rev().collect();
+        return Ok(());
     }
 
-    pub fn u8_to_oct(input: u8) -> String {
-        let mut input: u8 = input;
-        let mut output: String = String::new();
-        for _x in 0..3 {
-            let y: u8 = input as u8 & 0b111;
-            input >>= 3;
-            output.push((y + b'0') as char);
+    /// 输入结束, 处理pending剩下的部分.
+    fn finish(&mut self) -> Result<(), String> {
+        match self.codec {
+            Codec::Base64Encode(config) => {
+                let text: String =
+                    Base64Encoder::encode_with(&self.pending, &config.set_line_width(0));
+                self.pending.clear();
+                self.wrap(text.as_bytes(), config.line_width());
+            }
+            Codec::Base64Decode(config) => {
+                let length: usize = self.pending.len();
+                self.decode_base64(length, &config)?;
+            }
+            Codec::HexEncode | Codec::HexDecode => {
+                if self.pending.len() != 0 {
+                    return Err(iceyee_error::c!("无效的长度"));
+                }
+            }
         }
-        return output.chars().rev().collect();
+        return Ok(());
     }
 
-    pub fn u8_to_oct_(input: u8) -> String {
-        let mut input: u8 = input;
-        let mut output: String = String::new();
-        for x in 0..3 {
-            if x != 0 && false {
-                output.push('_');
+    /// 同[Base64Encoder::encode_with]的换行, 只是跨越多次调用.
+    fn wrap(&mut self, text: &[u8], line_width: usize) {
+        for c in text {
+            if line_width != 0 && self.column == line_width {
+                self.output.extend_from_slice(b"\r\n");
+                self.column = 0;
             }
-            let y: u8 = input as u8 & 0b111;
-            input >>= 3;
-            output.push((y + b'0') as char);
+            self.output.push(*c);
+            self.column += 1;
         }
-        output.push('o');
-        output.push('0');
-        return output.chars().rev().collect();
+        return;
     }
 
-    pub fn u64_to_hex(input: u64) -> String {
-        let mut input: u64 = input;
-        let mut output: String = String::new();
-        for _x in 0..16 {
-            let y: u8 = input as u8 & 0xF;
-            input >>= 4;
-            if y < 10 {
-                output.push((y + b'0') as char);
-            } else {
-                output.push((y - 10 + b'A') as char);
-            }
+    fn decode_base64(&mut self, length: usize, config: &Base64Config) -> Result<(), String> {
+        let text: &str = std::str::from_utf8(&self.pending[..length])
+            .map_err(|_| iceyee_error::c!("出现未预期的字符"))?;
+        let data: Vec<u8> = Base64Encoder::decode_with(text, config)?;
+        if self.padded && (data.len() != 0 || text.contains('=')) {
+            /* '='只能在最后. */
+            return Err(iceyee_error::c!("出现未预期的字符"));
         }
-        return output.chars().rev().collect();
+        if text.contains('=') {
+            self.padded = true;
+        }
+        self.pending.drain(..length);
+        self.output.extend_from_slice(&data);
+        return Ok(());
     }
+}
 
-    pub fn u64_to_hex_(input: u64) -> String {
-        let mut input: u64 = input;
-        let mut output: String = String::new();
-        for x in 0..16 {
-            if x != 0 && x % 4 == 0 {
-                output.push('_');
-            }
-            let y: u8 = input as u8 & 0xF;
-            input >>= 4;
-            if y < 10 {
-                output.push((y + b'0') as char);
-            } else {
-                output.push((y - 10 + b'A') as char);
-            }
-        }
-        output.push('x');
-        output.push('0');
-        return output.chars().rev().collect();
+/// 流式编码或解码, 包装[AsyncRead].
+///
+/// 由[Base64Encoder::encode_reader], [Base64Encoder::decode_reader],
+/// [HexEncoder::encode_reader], [HexEncoder::decode_reader]创建,
+/// 每次只处理读到的一块, 内存占用与输入的大小无关.
+///
+/// 解码失败时返回[InvalidData](std::io::ErrorKind::InvalidData).
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::Base64Config;
+/// use iceyee_encoder::Base64Encoder;
+/// use tokio::io::AsyncReadExt;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut reader = Base64Encoder::encode_reader("hello".as_bytes(), &Base64Config::STANDARD);
+///     let mut output: String = String::new();
+///     reader.read_to_string(&mut output).await.unwrap();
+///     assert_eq!(output, "aGVsbG8=");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CodecReader<R> {
+    inner: R,
+    state: CodecState,
+    eof: bool,
+}
+
+impl<R> CodecReader<R>
+where
+    R: AsyncRead + Unpin,
+{
+    fn new(inner: R, codec: Codec) -> Self {
+        return CodecReader {
+            inner: inner,
+            state: CodecState::new(codec),
+            eof: false,
+        };
     }
 
-    pub fn u32_to_hex(input: u32) -> String {
-        let mut input: u32 = input;
-        let mut output: String = String::new();
-        for _x in 0..8 {
-            let y: u8 = input as u8 & 0xF;
-            input >>= 4;
-            if y < 10 {
-                output.push((y + b'0') as char);
-            } else {
-                output.push((y - 10 + b'A') as char);
-            }
-        }
-        return output.chars().rev().collect();
+    pub fn into_inner(self) -> R {
+        return self.inner;
     }
+}
 
-    pub fn u32_to_hex_(input: u32) -> String {
-        let mut input: u32 = input;
-        let mut output: String = String::new();
-        for x in 0..8 {
-            if x != 0 && x % 4 == 0 {
-                output.push('_');
+impl<R> AsyncRead for CodecReader<R>
+where
+    R: AsyncRead + Unpin,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<Result<(), StdIoError>> {
+        let this: &mut Self = self.as_mut().get_mut();
+        loop {
+            let state: &mut CodecState = &mut this.state;
+            if state.position < state.output.len() {
+                let length: usize = buf.remaining().min(state.output.len() - state.position);
+                buf.put_slice(&state.output[state.position..state.position + length]);
+                state.position += length;
+                if state.position == state.output.len() {
+                    state.output.clear();
+                    state.position = 0;
+                }
+                return Poll::Ready(Ok(()));
             }
-            let y: u8 = input as u8 & 0xF;
-            input >>= 4;
-            if y < 10 {
-                output.push((y + b'0') as char);
+            if this.eof {
+                return Poll::Ready(Ok(()));
+            }
+            let mut data: [u8; 0x2000] = [0; 0x2000];
+            let mut data: ReadBuf<'_> = ReadBuf::new(&mut data);
+            match Pin::new(&mut this.inner).poll_read(cx, &mut data) {
+                Poll::Ready(Ok(())) => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+            let result: Result<(), String> = if data.filled().len() == 0 {
+                this.eof = true;
+                state.finish()
             } else {
-                output.push((y - 10 + b'A') as char);
+                state.pending.extend_from_slice(data.filled());
+                state.update()
+            };
+            if let Err(e) = result {
+                return Poll::Ready(Err(StdIoError::new(StdIoErrorKind::InvalidData, e)));
             }
         }
-        output.push('x');
-        output.push('0');
-        return output.chars().rev().collect();
     }
+}
 
-    pub fn u16_to_hex(input: u16) -> String {
-        let mut input: u16 = input;
-        let mut output: String = String::new();
-        for _x in 0..4 {
-            let y: u8 = input as u8 & 0xF;
-            input >>= 4;
-            if y < 10 {
-                output.push((y + b'0') as char);
-            } else {
-                output.push((y - 10 + b'A') as char);
+/// 流式编码或解码, 包装[AsyncWrite].
+///
+/// 由[Base64Encoder::encode_writer], [Base64Encoder::decode_writer],
+/// [HexEncoder::encode_writer], [HexEncoder::decode_writer]创建,
+/// 每次只处理写入的一块, 内存占用与输入的大小无关.
+///
+/// 最后必须调用`shutdown()`, 才会输出不足一组的部分, 比如Base64的'='.
+///
+/// 解码失败时返回[InvalidData](std::io::ErrorKind::InvalidData).
+///
+/// # Example
+/// ```
+/// use iceyee_encoder::HexEncoder;
+/// use tokio::io::AsyncWriteExt;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut writer = HexEncoder::encode_writer(Vec::<u8>::new());
+///     writer.write_all("hello".as_bytes()).await.unwrap();
+///     writer.shutdown().await.unwrap();
+///     assert_eq!(writer.into_inner(), "68656C6C6F".as_bytes());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CodecWriter<W> {
+    inner: W,
+    state: CodecState,
+    finished: bool,
+}
+
+impl<W> CodecWriter<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    fn new(inner: W, codec: Codec) -> Self {
+        return CodecWriter {
+            inner: inner,
+            state: CodecState::new(codec),
+            finished: false,
+        };
+    }
+
+    pub fn into_inner(self) -> W {
+        return self.inner;
+    }
+
+    /// 把output写到inner.
+    fn poll_drain(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), StdIoError>> {
+        let state: &mut CodecState = &mut self.state;
+        while state.position < state.output.len() {
+            match Pin::new(&mut self.inner).poll_write(cx, &state.output[state.position..]) {
+                Poll::Ready(Ok(0)) => {
+                    return Poll::Ready(Err(StdIoError::from(StdIoErrorKind::WriteZero)));
+                }
+                Poll::Ready(Ok(length)) => state.position += length,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
             }
         }
-        return output.chars().rev().collect();
+        state.output.clear();
+        state.position = 0;
+        return Poll::Ready(Ok(()));
     }
+}
 
-    pub fn u16_to_hex_(input: u16) -> String {
-        let mut input: u16 = input;
-        let mut output: String = String::new();
-        for x in 0..4 {
-            if x != 0 && x % 4 == 0 {
-                output.push('_');
-            }
-            let y: u8 = input as u8 & 0xF;
-            input >>= 4;
-            if y < 10 {
-                output.push((y + b'0') as char);
-            } else {
-                output.push((y - 10 + b'A') as char);
-            }
+impl<W> AsyncWrite for CodecWriter<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, StdIoError>> {
+        let this: &mut Self = self.as_mut().get_mut();
+        if this.finished {
+            return Poll::Ready(Err(StdIoError::from(StdIoErrorKind::BrokenPipe)));
         }
-        output.push('x');
-        output.push('0');
-        return output.chars().rev().collect();
+        match this.poll_drain(cx) {
+            Poll::Ready(Ok(())) => {}
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => return Poll::Pending,
+        }
+        let length: usize = buf.len().min(0x2000);
+        this.state.pending.extend_from_slice(&buf[..length]);
+        if let Err(e) = this.state.update() {
+            return Poll::Ready(Err(StdIoError::new(StdIoErrorKind::InvalidData, e)));
+        }
+        return Poll::Ready(Ok(length));
     }
 
-    pub fn u8_to_hex(input: u8) -> String {
-        let mut input: u8 = input;
-        let mut output: String = String::new();
-        for _x in 0..2 {
-            let y: u8 = input as u8 & 0xF;
-            input >>= 4;
-            if y < 10 {
-                output.push((y + b'0') as char);
-            } else {
-                output.push((y - 10 + b'A') as char);
-            }
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), StdIoError>> {
+        let this: &mut Self = self.as_mut().get_mut();
+        match this.poll_drain(cx) {
+            Poll::Ready(Ok(())) => {}
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => return Poll::Pending,
         }
-        return output.chars().rev().collect();
+        return Pin::new(&mut this.inner).poll_flush(cx);
     }
 
-    pub fn u8_to_hex_(input: u8) -> String {
-        let mut input: u8 = input;
-        let mut output: String = String::new();
-        for x in 0..2 {
-            if x != 0 && x % 4 == 0 {
-                output.push('_');
-            }
-            let y: u8 = input as u8 & 0xF;
-            input >>= 4;
-            if y < 10 {
-                output.push((y + b'0') as char);
-            } else {
-                output.push((y - 10 + b'A') as char);
+    fn poll_shutdown(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), StdIoError>> {
+        let this: &mut Self = self.as_mut().get_mut();
+        if !this.finished {
+            this.finished = true;
+            if let Err(e) = this.state.finish() {
+                return Poll::Ready(Err(StdIoError::new(StdIoErrorKind::InvalidData, e)));
             }
         }
-        output.push('x');
-        output.push('0');
-        return output.chars().rev().collect();
+        match this.poll_drain(cx) {
+            Poll::Ready(Ok(())) => {}
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => return Poll::Pending,
+        }
+        return Pin::new(&mut this.inner).poll_shutdown(cx);
     }
 }
 
-/// Url编码.
+/* Function. */
 
-#[derive(Debug, Clone)]
-pub struct UrlEncoder;
+/// 按名称选择编码, 不区分大小写, '_'等同于'-', 不支持的名称返回[None].
+///
+/// - base64, [Base64Config::STANDARD].
+/// - base64url, [Base64Config::URL_SAFE].
+/// - base64url-nopad, [Base64Config::URL_SAFE_NO_PAD].
+/// - base64-mime, [Base64Config::MIME].
+/// - base32, base58, ascii85, z85, hex, url, quoted-printable.
+pub fn find_encoding(name: &str) -> Option<&'static dyn Encoding> {
+    let name: String = name.trim().to_ascii_lowercase().replace('_', "-");
+    return match name.as_str() {
+        "base64" => Some(&Base64Config::STANDARD),
+        "base64url" => Some(&Base64Config::URL_SAFE),
+        "base64url-nopad" => Some(&Base64Config::URL_SAFE_NO_PAD),
+        "base64-mime" => Some(&Base64Config::MIME),
+        "base32" => Some(&Base32Encoder),
+        "base58" => Some(&Base58Encoder),
+        "ascii85" => Some(&Ascii85Encoder),
+        "z85" => Some(&Z85Encoder),
+        "hex" => Some(&HexEncoder),
+        "url" => Some(&UrlEncoder),
+        "quoted-printable" => Some(&QuotedPrintableEncoder),
+        _ => None,
+    };
+}
 
-impl UrlEncoder {
-    /// 编码.
-    pub fn encode(input: &str) -> String {
-        let input: String = input.to_string();
-        static TABLE: [char; 16] = [
-            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
-        ];
-        let mut output: String = String::new();
-        let input: &[u8] = input.as_bytes();
-        for x in input {
-            if *x == b' ' {
-                output.push('+');
-            } else if x.is_ascii_alphanumeric() || "$-_.".contains(*x as char) {
-                output.push(*x as char);
-            } else {
-                let high: u8 = (*x >> 4) & 0x0F;
-                let low: u8 = (*x >> 0) & 0x0F;
-                output.push('%');
-                output.push(TABLE[high as usize]);
-                output.push(TABLE[low as usize]);
-            }
+/// 每4个字节编码成5个字符, 大端, 最后n个字节编码成n+1个字符, 追加到output.
+fn base85_encode(input: &[u8], table: &[u8; 85], zero: bool, output: &mut String) {
+    for chunk in input.chunks(4) {
+        let mut buffer: [u8; 4] = [0; 4];
+        buffer[..chunk.len()].copy_from_slice(chunk);
+        let mut value: u32 = u32::from_be_bytes(buffer);
+        if zero && chunk.len() == 4 && value == 0 {
+            output.push('z');
+            continue;
+        }
+        let mut digits: [u8; 5] = [0; 5];
+        for x in (0..5).rev() {
+            digits[x] = table[(value % 85) as usize];
+            value /= 85;
+        }
+        for x in &digits[..chunk.len() + 1] {
+            output.push(*x as char);
         }
-        return output;
     }
+    return;
+}
 
-    /// 解码.
-    ///
-    /// - @exception 错误的格式.
-    /// - @exception 内容不是UTF-8编码.
-    pub fn decode(cipher: &str) -> Result<String, String> {
-        let cipher: String = cipher.to_string();
-        enum Status {
-            Normal,
-            High,
-            Low,
+/// 每5个数字解码成4个字节, 最后n个数字解码成n-1个字节.
+///
+/// - @exception 无效的长度.
+/// - @exception 数值溢出.
+fn base85_decode(values: &[u8], output: &mut Vec<u8>) -> Result<(), String> {
+    for chunk in values.chunks(5) {
+        if chunk.len() == 1 {
+            return Err(iceyee_error::c!("无效的长度"));
         }
-        let mut plain: Vec<u8> = Vec::new();
-        let cipher: &[u8] = cipher.as_bytes();
-        let mut status: Status = Status::Normal;
-        let mut high: u8 = 0;
-        let mut low: u8;
-        for x in cipher {
-            match status {
-                Status::Normal => match *x {
-                    b'%' => status = Status::High,
-                    b'+' => plain.push(b' '),
-                    _ => plain.push(*x),
-                },
-                Status::High => match *x {
-                    b'0'..=b'9' => {
-                        status = Status::Low;
-                        high = *x - b'0';
-                    }
-                    b'a'..=b'f' => {
-                        status = Status::Low;
-                        high = *x - b'a' + 10;
-                    }
-                    b'A'..=b'F' => {
-                        status = Status::Low;
-                        high = *x - b'A' + 10;
-                    }
-                    _ => return Err(iceyee_error::c!("错误的格式")),
-                },
-                Status::Low => match *x {
-                    b'0'..=b'9' => {
-                        status = Status::Normal;
-                        low = *x - b'0';
-                        plain.push((high << 4) | (low << 0));
-                    }
-                    b'a'..=b'f' => {
-                        status = Status::Normal;
-                        low = *x - b'a' + 10;
-                        plain.push((high << 4) | (low << 0));
-                    }
-                    b'A'..=b'F' => {
-                        status = Status::Normal;
-                        low = *x - b'A' + 10;
-                        plain.push((high << 4) | (low << 0));
-                    }
-                    _ => return Err(iceyee_error::c!("错误的格式")),
-                },
-            }
+        /* 不足5个的用最大的数字补齐. */
+        let mut value: u64 = 0;
+        for x in 0..5 {
+            value = value * 85 + *chunk.get(x).unwrap_or(&84) as u64;
         }
-        match status {
-            Status::Normal => {}
-            _ => return Err(iceyee_error::c!("错误的格式")),
+        if (u32::MAX as u64) < value {
+            return Err(iceyee_error::c!("数值溢出"));
         }
-        let plain: String =
-            String::from_utf8(plain).map_err(|_| iceyee_error::c!("内容不是UTF-8编码"))?;
-        return Ok(plain);
+        let bytes: [u8; 4] = (value as u32).to_be_bytes();
+        output.extend_from_slice(&bytes[..chunk.len() - 1]);
     }
+    return Ok(());
 }
-
-/* Function. */
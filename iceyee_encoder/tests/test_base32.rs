@@ -0,0 +1,56 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_encoder::Base32Encoder;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_base32_encoder() {
+    println!("");
+    let table = [
+        ("", ""),
+        ("f", "MY======"),
+        ("fo", "MZXQ===="),
+        ("foo", "MZXW6==="),
+        ("foob", "MZXW6YQ="),
+        ("fooba", "MZXW6YTB"),
+        ("foobar", "MZXW6YTBOI======"),
+    ];
+    println!("测试encode功能.");
+    for (x, y) in table {
+        println!("{x} <encode> {y}");
+        assert_eq!(Base32Encoder::encode(x.as_bytes()), y);
+    }
+    println!("测试decode功能.");
+    for (x, y) in table {
+        println!("{y} <decode> {x}");
+        assert_eq!(Base32Encoder::decode(y).expect("NEVER"), x.as_bytes());
+    }
+    println!("测试小写和省略'='.");
+    assert_eq!(
+        Base32Encoder::decode("mzxw6ytboi").expect("NEVER"),
+        "foobar".as_bytes()
+    );
+    println!("测试异常输入.");
+    assert_eq!(
+        Base32Encoder::decode("MZXW6Y").map_err(|x| x.contains("无效的长度")),
+        Err(true)
+    );
+    assert_eq!(
+        Base32Encoder::decode("MZXW6YT1").map_err(|x| x.contains("出现未预期的字符")),
+        Err(true)
+    );
+    return;
+}
@@ -0,0 +1,51 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_encoder::Base58Encoder;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_base58_encoder() {
+    println!("");
+    let table: [(&[u8], &str); 5] = [
+        (b"", ""),
+        (b"Hello World!", "2NEpo7TZRRrLZSi2U"),
+        (
+            b"The quick brown fox jumps over the lazy dog.",
+            "USm3fpXnKG5EUBx2ndxBDMPVciP5hGey2Jh4NDv6gmeo1LkMeiKrLJUUBk6Z",
+        ),
+        (&[0x00, 0x00, 0x28, 0x7F, 0xB4, 0xCD], "11233QC4"),
+        (&[0x00], "1"),
+    ];
+    println!("测试encode功能.");
+    for (x, y) in table {
+        println!("{:?} <encode> {y}", x);
+        assert_eq!(Base58Encoder::encode(x), y);
+    }
+    println!("测试decode功能.");
+    for (x, y) in table {
+        println!("{y} <decode> {:?}", x);
+        assert_eq!(Base58Encoder::decode(y).expect("NEVER"), x);
+    }
+    println!("测试异常输入.");
+    for x in ["0", "O", "I", "l", "2NEpo7TZRR+"] {
+        println!("{x}");
+        assert_eq!(
+            Base58Encoder::decode(x).map_err(|x| x.contains("出现未预期的字符")),
+            Err(true)
+        );
+    }
+    return;
+}
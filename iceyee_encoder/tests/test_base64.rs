@@ -6,6 +6,7 @@
 //
 // Use.
 
+use iceyee_encoder::Base64Config;
 use iceyee_encoder::Base64Encoder;
 
 // Enum.
@@ -71,3 +72,80 @@ pub fn test_base64_encoder() {
     );
     return;
 }
+
+#[test]
+pub fn test_base64_config() {
+    println!("");
+    println!("测试URL安全的字母表.");
+    let input: Vec<u8> = vec![0xFB, 0xEF, 0xFF, 0xFB];
+    assert_eq!(Base64Encoder::encode(&input), "++//+w==");
+    assert_eq!(
+        Base64Encoder::encode_with(&input, &Base64Config::URL_SAFE),
+        "--__-w=="
+    );
+    assert_eq!(
+        Base64Encoder::encode_with(&input, &Base64Config::URL_SAFE_NO_PAD),
+        "--__-w"
+    );
+    assert_eq!(
+        Base64Encoder::decode_with("--__-w", &Base64Config::URL_SAFE_NO_PAD).expect("NEVER"),
+        input
+    );
+    assert_eq!(
+        Base64Encoder::decode_with("--__-w==", &Base64Config::URL_SAFE).expect("NEVER"),
+        input
+    );
+    assert!(Base64Encoder::decode_with("++//+w==", &Base64Config::URL_SAFE).is_err());
+    assert!(Base64Encoder::decode_with("--__-w==", &Base64Config::URL_SAFE_NO_PAD).is_err());
+    assert!(Base64Encoder::decode_with("--__-w", &Base64Config::URL_SAFE).is_err());
+    println!("测试与encode, decode一致.");
+    for x in 0..0x40 {
+        let input: Vec<u8> = (0..x).map(|y| (y * 37 + x) as u8).collect();
+        let a001: String = Base64Encoder::encode(&input);
+        assert_eq!(
+            Base64Encoder::encode_with(&input, &Base64Config::STANDARD),
+            a001
+        );
+        assert_eq!(
+            Base64Encoder::decode_with(&a001, &Base64Config::STANDARD).expect("NEVER"),
+            input
+        );
+    }
+    println!("测试宽松的解码.");
+    let config: Base64Config = Base64Config::new().set_lenient(true);
+    assert_eq!(
+        Base64Encoder::decode_with(" aGVs\tbG8g\r\nd29y bA ", &config).expect("NEVER"),
+        "hello worl".as_bytes()
+    );
+    assert!(Base64Encoder::decode_with(" aGVsbG8g", &Base64Config::STANDARD).is_err());
+    assert_eq!(
+        Base64Encoder::decode_with("12345", &config).map_err(|x| x.contains("无效的长度")),
+        Err(true)
+    );
+    assert_eq!(
+        Base64Encoder::decode_with("12=3", &config).map_err(|x| x.contains("出现未预期的字符")),
+        Err(true)
+    );
+    println!("测试MIME换行.");
+    let input: Vec<u8> = vec![b'a'; 100];
+    let a002: String = Base64Encoder::encode_with(&input, &Base64Config::MIME);
+    println!("{}", a002);
+    let lines: Vec<&str> = a002.split("\r\n").collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].len(), 76);
+    assert_eq!(lines.concat(), Base64Encoder::encode(&input));
+    assert_eq!(
+        Base64Encoder::decode_with(&a002, &Base64Config::MIME).expect("NEVER"),
+        input
+    );
+    let config: Base64Config = Base64Config::new().set_line_width(4);
+    assert_eq!(
+        Base64Encoder::encode_with("hello".as_bytes(), &config),
+        "aGVs\r\nbG8="
+    );
+    assert_eq!(
+        Base64Encoder::decode_with("aGVs\r\nbG8=", &config).expect("NEVER"),
+        "hello".as_bytes()
+    );
+    return;
+}
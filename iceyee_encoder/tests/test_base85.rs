@@ -0,0 +1,101 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_encoder::Ascii85Encoder;
+use iceyee_encoder::Z85Encoder;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_ascii85_encoder() {
+    println!("");
+    let table: [(&[u8], &str); 6] = [
+        (b"", ""),
+        (b"Man ", "9jqo^"),
+        (b"sure.", "F*2M7/c"),
+        (b"pleasure.", "E,9)oF*2M7/c"),
+        (&[0, 0, 0, 0, 1], "z!<"),
+        (&[0, 0, 0], "!!!!"),
+    ];
+    println!("测试encode功能.");
+    for (x, y) in table {
+        println!("{:?} <encode> {y}", x);
+        assert_eq!(Ascii85Encoder::encode(x), y);
+    }
+    println!("测试decode功能.");
+    for (x, y) in table {
+        println!("{y} <decode> {:?}", x);
+        assert_eq!(Ascii85Encoder::decode(y).expect("NEVER"), x);
+    }
+    println!("测试'<~', '~>'和空白字符.");
+    assert_eq!(
+        Ascii85Encoder::decode("<~9jqo^\r\nF*2M7/c~>").expect("NEVER"),
+        "Man sure.".as_bytes()
+    );
+    println!("测试异常输入.");
+    assert_eq!(
+        Ascii85Encoder::decode("9jqo^F").map_err(|x| x.contains("无效的长度")),
+        Err(true)
+    );
+    assert_eq!(
+        Ascii85Encoder::decode("9jzqo").map_err(|x| x.contains("出现未预期的字符")),
+        Err(true)
+    );
+    assert_eq!(
+        Ascii85Encoder::decode("uuuuu").map_err(|x| x.contains("数值溢出")),
+        Err(true)
+    );
+    return;
+}
+
+#[test]
+pub fn test_z85_encoder() {
+    println!("");
+    let table: [(&[u8], &str); 3] = [
+        (b"", ""),
+        (
+            &[0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B],
+            "HelloWorld",
+        ),
+        (&[0, 0, 0, 0], "00000"),
+    ];
+    println!("测试encode功能.");
+    for (x, y) in table {
+        println!("{:?} <encode> {y}", x);
+        assert_eq!(Z85Encoder::encode(x), y);
+    }
+    println!("测试decode功能.");
+    for (x, y) in table {
+        println!("{y} <decode> {:?}", x);
+        assert_eq!(Z85Encoder::decode(y).expect("NEVER"), x);
+    }
+    println!("测试长度不是4的倍数.");
+    for x in 0..0x10 {
+        let input: Vec<u8> = (0..x).map(|y| (y * 61 + 0xF0) as u8).collect();
+        assert_eq!(
+            Z85Encoder::decode(&Z85Encoder::encode(&input)).expect("NEVER"),
+            input
+        );
+    }
+    println!("测试异常输入.");
+    assert_eq!(
+        Z85Encoder::decode("Hello World").map_err(|x| x.contains("出现未预期的字符")),
+        Err(true)
+    );
+    assert_eq!(
+        Z85Encoder::decode("HelloW").map_err(|x| x.contains("无效的长度")),
+        Err(true)
+    );
+    return;
+}
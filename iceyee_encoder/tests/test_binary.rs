@@ -0,0 +1,217 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_encoder::BinaryReader;
+use iceyee_encoder::BinaryWriter;
+use iceyee_encoder::TlvEncoder;
+use iceyee_encoder::TlvFrame;
+use iceyee_encoder::VarintEncoder;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_varint() {
+    println!("");
+    println!("无符号.");
+    let table: [(u64, &[u8]); 6] = [
+        (0, &[0x00]),
+        (1, &[0x01]),
+        (127, &[0x7F]),
+        (128, &[0x80, 0x01]),
+        (300, &[0xAC, 0x02]),
+        (
+            u64::MAX,
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+        ),
+    ];
+    for (value, bytes) in table {
+        assert_eq!(VarintEncoder::encode_unsigned(value), bytes);
+        assert_eq!(
+            VarintEncoder::decode_unsigned(bytes).expect("NEVER"),
+            (value, bytes.len())
+        );
+    }
+    println!("有符号, zigzag.");
+    let table: [(i64, u64); 6] = [
+        (0, 0),
+        (-1, 1),
+        (1, 2),
+        (-2, 3),
+        (i64::MAX, u64::MAX - 1),
+        (i64::MIN, u64::MAX),
+    ];
+    for (value, zigzag) in table {
+        assert_eq!(VarintEncoder::zigzag_encode(value), zigzag);
+        assert_eq!(VarintEncoder::zigzag_decode(zigzag), value);
+        let bytes: Vec<u8> = VarintEncoder::encode_signed(value);
+        assert_eq!(bytes, VarintEncoder::encode_unsigned(zigzag));
+        assert_eq!(
+            VarintEncoder::decode_signed(&bytes).expect("NEVER"),
+            (value, bytes.len())
+        );
+    }
+    println!("异常输入.");
+    for bytes in [&[][..], &[0x80], &[0xFF, 0xFF]] {
+        assert_eq!(
+            VarintEncoder::decode_unsigned(bytes).map_err(|x| x.contains("无效的长度")),
+            Err(true)
+        );
+    }
+    let bytes: [u8; 10] = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02];
+    assert_eq!(
+        VarintEncoder::decode_unsigned(&bytes).map_err(|x| x.contains("数值溢出")),
+        Err(true)
+    );
+    let bytes: [u8; 11] = [
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00,
+    ];
+    assert_eq!(
+        VarintEncoder::decode_signed(&bytes).map_err(|x| x.contains("数值溢出")),
+        Err(true)
+    );
+    return;
+}
+
+#[test]
+pub fn test_binary_reader_writer() {
+    println!("");
+    println!("写.");
+    let mut writer: BinaryWriter = BinaryWriter::new();
+    assert!(writer.is_empty());
+    writer.write_u8(0xAB);
+    writer.write_i8(-1);
+    writer.write_u16_be(0x0102);
+    writer.write_u16_le(0x0102);
+    writer.write_u32_be(0x01020304);
+    writer.write_i32_le(-2);
+    writer.write_u64_be(0x0102030405060708);
+    writer.write_i64_le(i64::MIN);
+    writer.write_u128_le(1);
+    writer.write_f32_be(1.5);
+    writer.write_f64_le(-0.25);
+    writer.write_varint_unsigned(300);
+    writer.write_varint_signed(-3);
+    writer.write_bytes("end".as_bytes());
+    assert_eq!(
+        writer.len(),
+        1 + 1 + 2 + 2 + 4 + 4 + 8 + 8 + 16 + 4 + 8 + 2 + 1 + 3
+    );
+    assert_eq!(
+        &writer.as_bytes()[..12],
+        &[
+            0xAB, 0xFF, 0x01, 0x02, 0x02, 0x01, 0x01, 0x02, 0x03, 0x04, 0xFE, 0xFF
+        ]
+    );
+    let bytes: Vec<u8> = writer.into_inner();
+    println!("读.");
+    let mut reader: BinaryReader = BinaryReader::new(&bytes);
+    assert_eq!(reader.read_u8(), Ok(0xAB));
+    assert_eq!(reader.read_i8(), Ok(-1));
+    assert_eq!(reader.read_u16_be(), Ok(0x0102));
+    assert_eq!(reader.read_u16_le(), Ok(0x0102));
+    assert_eq!(reader.read_u32_be(), Ok(0x01020304));
+    assert_eq!(reader.read_i32_le(), Ok(-2));
+    assert_eq!(reader.read_u64_be(), Ok(0x0102030405060708));
+    assert_eq!(reader.read_i64_le(), Ok(i64::MIN));
+    assert_eq!(reader.read_u128_le(), Ok(1));
+    assert_eq!(reader.read_f32_be(), Ok(1.5));
+    assert_eq!(reader.read_f64_le(), Ok(-0.25));
+    assert_eq!(reader.read_varint_unsigned(), Ok(300));
+    assert_eq!(reader.read_varint_signed(), Ok(-3));
+    assert_eq!(reader.remaining(), 3);
+    println!("数据不足时失败, 位置不变.");
+    assert_eq!(
+        reader.read_u32_be().map_err(|x| x.contains("无效的长度")),
+        Err(true)
+    );
+    assert_eq!(reader.position(), bytes.len() - 3);
+    assert_eq!(reader.rest(), "end".as_bytes());
+    assert_eq!(reader.read_bytes(3), Ok("end".as_bytes()));
+    assert!(reader.read_varint_unsigned().is_err());
+    assert_eq!(reader.remaining(), 0);
+    println!("追加.");
+    let mut writer: BinaryWriter = BinaryWriter::from_vec(vec![1]);
+    writer.write_u16_le(2);
+    assert_eq!(writer.into_inner(), vec![1, 2, 0]);
+    return;
+}
+
+#[test]
+pub fn test_tlv() {
+    println!("");
+    println!("编码和解码.");
+    let long: Vec<u8> = vec![7; 200];
+    let mut output: Vec<u8> = TlvEncoder::encode(1, "hello".as_bytes());
+    TlvEncoder::write(2, &[], &mut output);
+    TlvEncoder::write(u64::MAX, &long, &mut output);
+    assert_eq!(
+        &output[..9],
+        &[0x01, 0x05, b'h', b'e', b'l', b'l', b'o', 0x02, 0x00]
+    );
+    let frames: Vec<(u64, Vec<u8>)> = TlvEncoder::decode(&output).expect("NEVER");
+    assert_eq!(
+        frames,
+        vec![
+            (1, "hello".as_bytes().to_vec()),
+            (2, vec![]),
+            (u64::MAX, long.clone())
+        ]
+    );
+    println!("分帧, 数据不完整时返回None.");
+    for length in 0..output.len() {
+        let mut input: &[u8] = &output[..length];
+        let mut count: usize = 0;
+        while let Some(frame) = TlvEncoder::decode_frame(input).expect("NEVER") {
+            input = &input[frame.length..];
+            count += 1;
+        }
+        assert!(count < 3);
+        if !input.is_empty() {
+            assert_eq!(
+                TlvEncoder::decode(&output[..length]).map_err(|x| x.contains("无效的长度")),
+                Err(true)
+            );
+        }
+    }
+    let frame: TlvFrame = TlvEncoder::decode_frame(&output)
+        .expect("NEVER")
+        .expect("NEVER");
+    assert_eq!(
+        frame,
+        TlvFrame {
+            tag: 1,
+            value: "hello".as_bytes(),
+            length: 7
+        }
+    );
+    println!("类型溢出.");
+    let bytes: [u8; 11] = [
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F, 0x00,
+    ];
+    assert_eq!(
+        TlvEncoder::decode_frame(&bytes).map_err(|x| x.contains("数值溢出")),
+        Err(true)
+    );
+    println!("长度溢出, 以及变长整数本身不完整.");
+    let mut bytes: Vec<u8> = vec![0x01];
+    bytes.extend_from_slice(&[0xFF; 9]);
+    bytes.push(0x02);
+    assert_eq!(
+        TlvEncoder::decode_frame(&bytes).map_err(|x| x.contains("数值溢出")),
+        Err(true)
+    );
+    assert_eq!(TlvEncoder::decode_frame(&[0xFF; 5]), Ok(None));
+    assert_eq!(TlvEncoder::decode_frame(&[0x01, 0xFF, 0xFF]), Ok(None));
+    return;
+}
@@ -0,0 +1,125 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_encoder::Base32Encoder;
+use iceyee_encoder::Base64Config;
+use iceyee_encoder::Base64Encoder;
+use iceyee_encoder::Encoding;
+use iceyee_encoder::HexEncoder;
+use iceyee_encoder::UrlEncoder;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+const NAMES: [&str; 11] = [
+    "base64",
+    "base64url",
+    "base64url-nopad",
+    "base64-mime",
+    "base32",
+    "base58",
+    "ascii85",
+    "z85",
+    "hex",
+    "url",
+    "quoted-printable",
+];
+
+fn data(length: usize) -> Vec<u8> {
+    return (0..length).map(|x| (x * 37 + x / 251) as u8).collect();
+}
+
+#[test]
+pub fn test_find_encoding() {
+    println!("");
+    println!("按名称选择, 不区分大小写.");
+    for name in NAMES {
+        let encoding: &dyn Encoding = iceyee_encoder::find_encoding(name).expect("NEVER");
+        assert_eq!(encoding.name(), name);
+        let upper: String = name.to_uppercase().replace('-', "_");
+        let encoding: &dyn Encoding = iceyee_encoder::find_encoding(&upper).expect("NEVER");
+        assert_eq!(encoding.name(), name);
+    }
+    assert!(iceyee_encoder::find_encoding("base16").is_none());
+    assert!(iceyee_encoder::find_encoding("").is_none());
+    println!("与原来的接口一致.");
+    let input: Vec<u8> = data(100);
+    let find = |name: &str| iceyee_encoder::find_encoding(name).expect("NEVER");
+    assert_eq!(find("base64").encode(&input), Base64Encoder::encode(&input));
+    assert_eq!(
+        find("base64url-nopad").encode(&input),
+        Base64Encoder::encode_with(&input, &Base64Config::URL_SAFE_NO_PAD)
+    );
+    assert_eq!(find("base32").encode(&input), Base32Encoder::encode(&input));
+    assert_eq!(find("hex").encode(&input), HexEncoder::encode(&input));
+    assert_eq!(
+        find("url").encode("a b&c".as_bytes()),
+        UrlEncoder::encode("a b&c")
+    );
+    assert_eq!(Base64Encoder.encode(&input), Base64Encoder::encode(&input));
+    assert_eq!(Base64Encoder.name(), "base64");
+    assert_eq!(
+        find("url").decode("%FF+%00").expect("NEVER"),
+        vec![0xFF, b' ', 0]
+    );
+    return;
+}
+
+#[test]
+pub fn test_encoding() {
+    println!("");
+    for name in NAMES {
+        println!("{}.", name);
+        let encoding: &dyn Encoding = iceyee_encoder::find_encoding(name).expect("NEVER");
+        for length in [0, 1, 2, 3, 4, 5, 57, 100, 1000] {
+            let mut input: Vec<u8> = data(length);
+            if name == "quoted-printable" {
+                /* '\n'是换行, 会编码成'\r\n'. */
+                input.retain(|x| *x != b'\n');
+            }
+            let output: String = encoding.encode(&input);
+            assert!(output.len() <= encoding.encoded_length(length));
+            assert!(length <= encoding.decoded_length(output.len()));
+            assert_eq!(encoding.decode(&output).expect("NEVER"), input);
+            println!("encode_to追加到后面, 容量足够时不分配内存.");
+            if name != "base58" {
+                let mut buffer: String = String::with_capacity(2 + encoding.encoded_length(length));
+                buffer.push_str("<<");
+                let capacity: usize = buffer.capacity();
+                let pointer: *const u8 = buffer.as_ptr();
+                encoding.encode_to(&input, &mut buffer);
+                assert_eq!(buffer.capacity(), capacity);
+                assert_eq!(buffer.as_ptr(), pointer);
+                assert_eq!(&buffer[2..], output);
+            }
+            let mut buffer: Vec<u8> = vec![1, 2];
+            encoding.decode_to(&output, &mut buffer).expect("NEVER");
+            assert_eq!(&buffer[..2], &[1, 2]);
+            assert_eq!(&buffer[2..], &input[..]);
+        }
+    }
+    println!("异常输入.");
+    let encoding: &dyn Encoding = iceyee_encoder::find_encoding("hex").expect("NEVER");
+    assert_eq!(
+        encoding.decode("123").map_err(|x| x.contains("无效的长度")),
+        Err(true)
+    );
+    let mut buffer: Vec<u8> = Vec::new();
+    assert_eq!(
+        encoding
+            .decode_to("12G4", &mut buffer)
+            .map_err(|x| x.contains("出现未预期的字符")),
+        Err(true)
+    );
+    return;
+}
@@ -0,0 +1,80 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_encoder::Gb18030Encoder;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_gb18030() {
+    println!("");
+    println!("单字节, 双字节(GB2312, GBK), 四字节(BMP, 辅助平面).");
+    let table: [(&str, &[u8]); 10] = [
+        ("hello", b"hello"),
+        ("中文", &[0xD6, 0xD0, 0xCE, 0xC4]),
+        ("乂", &[0x81, 0x56]),
+        ("〇", &[0xA9, 0x96]),
+        ("€", &[0xA2, 0xE3]),
+        ("\u{80}", &[0x81, 0x30, 0x81, 0x30]),
+        ("ｱ", &[0x84, 0x31, 0x97, 0x33]),
+        ("\u{FFFF}", &[0x84, 0x31, 0xA4, 0x39]),
+        ("\u{10000}", &[0x90, 0x30, 0x81, 0x30]),
+        ("\u{10FFFF}", &[0xE3, 0x32, 0x9A, 0x35]),
+    ];
+    for (text, bytes) in table {
+        assert_eq!(Gb18030Encoder::encode(text), bytes);
+        assert_eq!(Gb18030Encoder::decode(bytes).expect("NEVER"), text);
+    }
+    println!("混合.");
+    let text: &str = "GB18030: 你好, 世界! €½😀\r\n";
+    let bytes: Vec<u8> = Gb18030Encoder::encode(text);
+    println!("{:02X?}", bytes);
+    assert_eq!(Gb18030Encoder::decode(&bytes).expect("NEVER"), text);
+    assert_eq!(Gb18030Encoder::decode_lossy(&bytes), text);
+    println!("异常输入.");
+    for bytes in [
+        &[0x81][..],
+        &[0xC4, 0xE3, 0xBA],
+        &[0x81, 0x30],
+        &[0x81, 0x30, 0x81],
+    ] {
+        assert_eq!(
+            Gb18030Encoder::decode(bytes).map_err(|x| x.contains("无效的长度")),
+            Err(true)
+        );
+    }
+    for bytes in [
+        &[0x80][..],
+        &[0xFF],
+        &[0x81, 0x20],
+        &[0x81, 0x30, 0x20, 0x30],
+        &[0x84, 0x31, 0xA5, 0x30],
+        &[0xE3, 0x32, 0x9A, 0x36],
+    ] {
+        assert_eq!(
+            Gb18030Encoder::decode(bytes).map_err(|x| x.contains("出现未预期的字符")),
+            Err(true)
+        );
+    }
+    println!("decode_lossy, 只跳过无效的第一个字节.");
+    assert_eq!(
+        Gb18030Encoder::decode_lossy(&[0x81, b' ', 0xD6, 0xD0]),
+        "\u{FFFD} 中"
+    );
+    assert_eq!(
+        Gb18030Encoder::decode_lossy(&[b'a', 0x80, 0xFF]),
+        "a\u{FFFD}\u{FFFD}"
+    );
+    return;
+}
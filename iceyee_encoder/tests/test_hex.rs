@@ -7,6 +7,7 @@
 // Use.
 
 use iceyee_encoder::HexEncoder;
+use iceyee_encoder::HexdumpConfig;
 
 // Enum.
 
@@ -88,3 +89,82 @@ fn test_hex_encoder() {
     );
     return;
 }
+
+#[test]
+fn test_hexdump() {
+    println!("");
+    println!("测试hexdump功能.");
+    let mut input: Vec<u8> = "hello world.\n".as_bytes().to_vec();
+    input.extend_from_slice(&[0; 42]);
+    input.extend_from_slice("abc|".as_bytes());
+    let output: String = HexEncoder::hexdump(&input, &HexdumpConfig::new());
+    println!("{}", output);
+    assert_eq!(
+        output,
+        "\
+00000000  68 65 6c 6c 6f 20 77 6f  72 6c 64 2e 0a 00 00 00  |hello world.....|
+00000010  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|
+00000020  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|
+00000030  00 00 00 00 00 00 00 61  62 63 7c                 |.......abc||
+0000003b
+"
+    );
+    assert_eq!(HexEncoder::parse_hexdump(&output).expect("NEVER"), input);
+    let config: HexdumpConfig = HexdumpConfig::new().set_width(4).set_uppercase(true);
+    let output: String = HexEncoder::hexdump(&[0xAB, 0xCD, 0xEF, 0x12, 0x34], &config);
+    println!("{}", output);
+    assert_eq!(
+        output,
+        "00000000  AB CD EF 12  |....|\n00000004  34           |4|\n00000005\n"
+    );
+    assert_eq!(
+        HexEncoder::parse_hexdump(&output).expect("NEVER"),
+        vec![0xAB, 0xCD, 0xEF, 0x12, 0x34]
+    );
+    assert_eq!(HexEncoder::hexdump(&[], &HexdumpConfig::new()), "");
+    println!("测试hexdump -C省略的重复行.");
+    let output: &str = "\
+00000000  68 65 6c 6c 6f 20 77 6f  72 6c 64 2e 0a 00 00 00  |hello world.....|
+00000010  00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00  |................|
+*
+00000030  00 00 00 00 00 00 00 61  62 63 7c                 |.......abc||
+0000003b
+";
+    assert_eq!(HexEncoder::parse_hexdump(output).expect("NEVER"), input);
+    println!("测试xxd的输出.");
+    let output: &str = "\
+00000000: 6865 6c6c 6f20 776f 726c 642e 0a00 0000  hello world.....
+00000010: 0000 0000 0000 0000 0000 0000 0000 0000  ................
+00000020: 0000 0000 0000 0000 0000 0000 0000 0000  ................
+00000030: 0000 0000 0000 0061 6263 7c              .......abc|
+";
+    assert_eq!(HexEncoder::parse_hexdump(output).expect("NEVER"), input);
+    println!("测试异常输入.");
+    assert_eq!(
+        HexEncoder::parse_hexdump("00000000  68 65\n00000003  6c\n")
+            .map_err(|x| x.contains("偏移量不连续")),
+        Err(true)
+    );
+    println!("'*'省略的不是整数个重复行.");
+    assert_eq!(
+        HexEncoder::parse_hexdump("00000000  41 42\n*\n00000005\n")
+            .map_err(|x| x.contains("偏移量不连续")),
+        Err(true)
+    );
+    println!("'*'之后的偏移量过大.");
+    assert_eq!(
+        HexEncoder::parse_hexdump("00000000  41\n*\nffffffffffff\n")
+            .map_err(|x| x.contains("数据过大")),
+        Err(true)
+    );
+    assert_eq!(
+        HexEncoder::parse_hexdump("hello").map_err(|x| x.contains("错误的格式")),
+        Err(true)
+    );
+    assert_eq!(
+        HexEncoder::parse_hexdump("00000000  68 6g  |h|")
+            .map_err(|x| x.contains("出现未预期的字符")),
+        Err(true)
+    );
+    return;
+}
@@ -0,0 +1,63 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_encoder::HtmlEncoder;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_html_encoder() {
+    println!("");
+    let table = [
+        ("hello 你好", "hello 你好"),
+        (
+            "<script>alert('x')</script>",
+            "&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;",
+        ),
+        ("\" onclick=\"x", "&quot; onclick=&quot;x"),
+        ("a&b", "a&amp;b"),
+    ];
+    println!("测试encode功能.");
+    for (x, y) in table {
+        println!("{x} <encode> {y}");
+        assert_eq!(HtmlEncoder::encode(x), y);
+    }
+    println!("测试decode功能.");
+    for (x, y) in table {
+        println!("{y} <decode> {x}");
+        assert_eq!(HtmlEncoder::decode(y), x);
+    }
+    let table = [
+        ("&#x27;&#X27;&#39;&apos;", "''''"),
+        ("&#128512;&#x1F600;", "😀😀"),
+        ("&copy; &mdash; &euro;", "© — €"),
+        (
+            "&unknown; & &amp &#xD800; &#;",
+            "&unknown; & &amp &#xD800; &#;",
+        ),
+        ("&&lt;", "&<"),
+    ];
+    for (x, y) in table {
+        println!("{x} <decode> {y}");
+        assert_eq!(HtmlEncoder::decode(x), y);
+    }
+    println!("测试大量的'&', 每个'&'只向后查找有限的长度.");
+    let input: String = "&".repeat(1_000_000) + "&amp;";
+    let output: String = HtmlEncoder::decode(&input);
+    assert_eq!(output.len(), 1_000_001);
+    assert!(output.ends_with("&&"));
+    let input: String = format!("&{};", "a".repeat(40));
+    assert_eq!(HtmlEncoder::decode(&input), input);
+    return;
+}
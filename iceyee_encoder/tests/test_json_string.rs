@@ -0,0 +1,69 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_encoder::JsonStringEncoder;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_json_string_encoder() {
+    println!("");
+    let table = [
+        ("hello 你好", "hello 你好"),
+        ("a\"b\\c/d", "a\\\"b\\\\c\\/d"),
+        ("\u{8}\u{c}\n\r\t", "\\b\\f\\n\\r\\t"),
+        ("\u{0}\u{1f}\u{7f}\u{2028}", "\\u0000\\u001f\\u007f\\u2028"),
+        ("</script>", "<\\/script>"),
+    ];
+    println!("测试encode功能.");
+    for (x, y) in table {
+        println!("{x:?} <encode> {y}");
+        assert_eq!(JsonStringEncoder::encode(x), y);
+    }
+    println!("测试decode功能.");
+    for (x, y) in table {
+        println!("{y} <decode> {x:?}");
+        assert_eq!(JsonStringEncoder::decode(y).expect("NEVER"), x);
+    }
+    println!("测试encode_ascii功能.");
+    assert_eq!(
+        JsonStringEncoder::encode_ascii("你好😀\n"),
+        "\\u4f60\\u597d\\ud83d\\ude00\\n"
+    );
+    assert_eq!(
+        JsonStringEncoder::decode("\\u4F60\\u597d\\uD83D\\uDE00\\n").expect("NEVER"),
+        "你好😀\n"
+    );
+    println!("测试与serde_json一致.");
+    for x in ["a\"b\\c", "\u{1}\u{1f}", "你好😀", "\u{7f}"] {
+        let output: String = format!("\"{}\"", JsonStringEncoder::encode(x));
+        assert_eq!(serde_json::from_str::<String>(&output).expect("NEVER"), x);
+    }
+    println!("测试异常输入.");
+    for (x, y) in [
+        ("\\x", "错误的格式"),
+        ("\\u12", "错误的格式"),
+        ("\\ud83d", "无效的代理对"),
+        ("\\ud83d\\u0041", "无效的代理对"),
+        ("\\ude00", "无效的代理对"),
+        ("a\nb", "出现未预期的字符"),
+    ] {
+        println!("{x:?}");
+        assert_eq!(
+            JsonStringEncoder::decode(x).map_err(|e| e.contains(y)),
+            Err(true)
+        );
+    }
+    return;
+}
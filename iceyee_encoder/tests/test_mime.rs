@@ -0,0 +1,120 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_encoder::EncodedWordEncoder;
+use iceyee_encoder::QuotedPrintableEncoder;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_quoted_printable_encoder() {
+    println!("");
+    let table = [
+        ("", ""),
+        ("hello world", "hello world"),
+        ("a=b", "a=3Db"),
+        ("你好", "=E4=BD=A0=E5=A5=BD"),
+        ("end ", "end=20"),
+        ("tab\t\r\nnext", "tab=09\r\nnext"),
+        ("line\nnext", "line\r\nnext"),
+    ];
+    println!("测试encode功能.");
+    for (x, y) in table {
+        println!("{x:?} <encode> {y:?}");
+        assert_eq!(QuotedPrintableEncoder::encode(x.as_bytes()), y);
+    }
+    println!("测试decode功能.");
+    for (x, y) in table {
+        println!("{y:?} <decode> {x:?}");
+        let x: String = x.replace("\r\n", "\n").replace("\n", "\r\n");
+        assert_eq!(
+            QuotedPrintableEncoder::decode(y).expect("NEVER"),
+            x.as_bytes()
+        );
+    }
+    println!("测试软换行.");
+    let input: String = "你好, 世界. ".repeat(20);
+    let output: String = QuotedPrintableEncoder::encode(input.as_bytes());
+    println!("{}", output);
+    for line in output.split("\r\n") {
+        assert!(line.len() <= 76);
+    }
+    assert!(output.contains("=\r\n"));
+    assert_eq!(
+        QuotedPrintableEncoder::decode(&output).expect("NEVER"),
+        input.as_bytes()
+    );
+    assert_eq!(
+        QuotedPrintableEncoder::decode("soft=\nbreak  \nhard").expect("NEVER"),
+        "softbreak\nhard".as_bytes()
+    );
+    println!("测试异常输入.");
+    assert_eq!(
+        QuotedPrintableEncoder::decode("a=G0").map_err(|x| x.contains("错误的格式")),
+        Err(true)
+    );
+    assert_eq!(
+        QuotedPrintableEncoder::decode("a=4").map_err(|x| x.contains("错误的格式")),
+        Err(true)
+    );
+    return;
+}
+
+#[test]
+pub fn test_encoded_word_encoder() {
+    println!("");
+    println!("测试encode_b, encode_q功能.");
+    assert_eq!(EncodedWordEncoder::encode_b("你好"), "=?UTF-8?B?5L2g5aW9?=");
+    assert_eq!(
+        EncodedWordEncoder::encode_q("你好, a_b?"),
+        "=?UTF-8?Q?=E4=BD=A0=E5=A5=BD=2C_a=5Fb=3F?="
+    );
+    let input: String = "关于下周会议的安排, 请各位准时参加. ".repeat(4);
+    for output in [
+        EncodedWordEncoder::encode_b(&input),
+        EncodedWordEncoder::encode_q(&input),
+    ] {
+        println!("{}", output);
+        for word in output.split("\r\n ") {
+            assert!(word.len() <= 75);
+        }
+        assert_eq!(EncodedWordEncoder::decode(&output).expect("NEVER"), input);
+    }
+    println!("测试decode功能.");
+    let table = [
+        ("hello", "hello"),
+        ("Re: =?UTF-8?B?5L2g5aW9?=", "Re: 你好"),
+        ("=?utf-8?q?a_b?= =?UTF-8?B?Yw==?=", "a bc"),
+        ("=?UTF-8?B?5L2g?=\r\n =?UTF-8?B?5aW9?= !", "你好 !"),
+        ("=?UTF-8?Q?=E4=BD?= =?UTF-8?Q?=A0?=", "你"),
+        ("=?ISO-8859-1?Q?caf=E9?=", "café"),
+        ("=?UTF-8*zh?B?5L2g?=", "你"),
+        ("=?UTF-8?B?not closed", "=?UTF-8?B?not closed"),
+        ("=?GB2312?B?xOO6ww==?=", "你好"),
+        ("=?gbk?Q?=C4=E3?= =?GB18030?B?usM=?=", "你好"),
+        ("=?KOI8-R?B?5L2g?=", "=?KOI8-R?B?5L2g?="),
+        ("=?KOI8-R?B?5L2g?= =?UTF-8?B?5L2g?=", "=?KOI8-R?B?5L2g?= 你"),
+        ("=?UTF-8?B?5L2g?= =?UTF-8?X?5L2g?=", "你 =?UTF-8?X?5L2g?="),
+    ];
+    for (x, y) in table {
+        println!("{x:?} <decode> {y:?}");
+        assert_eq!(EncodedWordEncoder::decode(x).expect("NEVER"), y);
+    }
+    println!("测试异常输入.");
+    assert_eq!(
+        EncodedWordEncoder::decode("=?UTF-8?Q?=E4=B?=").map_err(|x| x.contains("错误的格式")),
+        Err(true)
+    );
+    return;
+}
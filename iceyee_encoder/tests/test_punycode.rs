@@ -0,0 +1,83 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_encoder::PunycodeEncoder;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_punycode_encoder() {
+    println!("");
+    let table = [
+        ("", ""),
+        ("abc", "abc-"),
+        ("bücher", "bcher-kva"),
+        ("münchen", "mnchen-3ya"),
+        ("例子", "fsqu00a"),
+        ("中国", "fiqs8s"),
+        ("他们为什么不说中文", "ihqwcrb4cv8a8dqg056pqjye"),
+        ("3年B組金八先生", "3B-ww4c5e180e575a65lsy2b"),
+        (
+            "Hello-Another-Way-それぞれの場所",
+            "Hello-Another-Way--fc4qua05auwb3674vfr0b",
+        ),
+    ];
+    println!("测试encode功能.");
+    for (x, y) in table {
+        println!("{x} <encode> {y}");
+        assert_eq!(PunycodeEncoder::encode(x).expect("NEVER"), y);
+    }
+    println!("测试decode功能.");
+    for (x, y) in table {
+        println!("{y} <decode> {x}");
+        assert_eq!(PunycodeEncoder::decode(y).expect("NEVER"), x);
+    }
+    println!("测试to_ascii, to_unicode功能.");
+    let table = [
+        ("www.example.com", "www.example.com"),
+        ("例子.中国", "xn--fsqu00a.xn--fiqs8s"),
+        ("www.bücher.de.", "www.xn--bcher-kva.de."),
+    ];
+    for (x, y) in table {
+        println!("{x} <to_ascii> {y}");
+        assert_eq!(PunycodeEncoder::to_ascii(x).expect("NEVER"), y);
+        assert_eq!(PunycodeEncoder::to_unicode(y).expect("NEVER"), x);
+    }
+    assert_eq!(
+        PunycodeEncoder::to_ascii("WWW.Bücher。DE").expect("NEVER"),
+        "www.xn--bcher-kva.de"
+    );
+    assert_eq!(
+        PunycodeEncoder::to_unicode("XN--FSQU00A.com").expect("NEVER"),
+        "例子.com"
+    );
+    println!("测试异常输入.");
+    assert_eq!(
+        PunycodeEncoder::to_ascii("a..b").map_err(|x| x.contains("无效的域名")),
+        Err(true)
+    );
+    assert_eq!(
+        PunycodeEncoder::to_ascii(&"a".repeat(64)).map_err(|x| x.contains("无效的域名")),
+        Err(true)
+    );
+    assert_eq!(
+        PunycodeEncoder::decode("a-b!").map_err(|x| x.contains("出现未预期的字符")),
+        Err(true)
+    );
+    assert_eq!(
+        PunycodeEncoder::decode("99999999999").map_err(|x| x.contains("数值溢出")),
+        Err(true)
+    );
+    return;
+}
@@ -6,6 +6,7 @@
 //
 // Use.
 
+use iceyee_encoder::RadixConfig;
 use iceyee_encoder::RadixEncoder;
 
 // Enum.
@@ -87,3 +88,114 @@ pub fn test_radix_encoder() {
     assert_eq!(RadixEncoder::u8_to_hex_(0xFA), "0xFA");
     return;
 }
+
+#[test]
+pub fn test_radix_config() {
+    println!("");
+    println!("测试2到36进制, 与std一致.");
+    for radix in 2..=36 {
+        let config: RadixConfig = RadixConfig::new(radix).expect("NEVER");
+        for x in [
+            0_u128,
+            1,
+            35,
+            0xFF,
+            0x1234_5678,
+            u64::MAX as u128,
+            u128::MAX,
+        ] {
+            let output: String = RadixEncoder::encode_unsigned(x, &config);
+            assert_eq!(
+                u128::from_str_radix(&output, radix).expect("NEVER"),
+                x,
+                "{radix} {output}"
+            );
+            assert_eq!(
+                RadixEncoder::decode_unsigned::<u128>(&output.to_lowercase(), &config),
+                Ok(x)
+            );
+        }
+    }
+    assert!(RadixConfig::new(1).is_err());
+    assert!(RadixConfig::new(37).is_err());
+    println!("测试分组, 前缀, 补0.");
+    let config: RadixConfig = RadixConfig::new(2)
+        .expect("NEVER")
+        .set_group(4)
+        .set_prefix(true);
+    assert_eq!(RadixEncoder::encode_unsigned(0xAC, &config), "0b1010_1100");
+    assert_eq!(
+        RadixEncoder::decode_unsigned::<u8>("0b1010_1100", &config),
+        Ok(0xAC)
+    );
+    let config: RadixConfig = RadixConfig::new(16)
+        .expect("NEVER")
+        .set_group(4)
+        .set_separator(' ')
+        .set_width(8)
+        .set_lowercase(true);
+    assert_eq!(RadixEncoder::encode_unsigned(0xABCDE, &config), "000a bcde");
+    assert_eq!(
+        RadixEncoder::decode_unsigned::<u32>("000a bcde", &config),
+        Ok(0xABCDE)
+    );
+    println!("测试有符号整数.");
+    let config: RadixConfig = RadixConfig::new(10).expect("NEVER");
+    assert_eq!(RadixEncoder::encode_signed(-128, &config), "-128");
+    assert_eq!(
+        RadixEncoder::encode_signed(i128::MIN, &config),
+        i128::MIN.to_string()
+    );
+    assert_eq!(RadixEncoder::decode_signed::<i8>("-128", &config), Ok(-128));
+    assert_eq!(RadixEncoder::decode_signed::<i8>("+127", &config), Ok(127));
+    assert_eq!(
+        RadixEncoder::decode_signed::<i128>(&i128::MIN.to_string(), &config),
+        Ok(i128::MIN)
+    );
+    println!("测试自定义字母表.");
+    let config: RadixConfig = RadixConfig::base62();
+    assert_eq!(config.radix(), 62);
+    assert_eq!(RadixEncoder::encode_unsigned(61, &config), "z");
+    assert_eq!(RadixEncoder::encode_unsigned(62, &config), "10");
+    assert_eq!(
+        RadixEncoder::decode_unsigned::<u64>("LygHa16AHYF", &config),
+        Ok(u64::MAX)
+    );
+    assert!(RadixConfig::with_alphabet("abca").is_err());
+    assert!(RadixConfig::with_alphabet("a").is_err());
+    let config: RadixConfig = RadixConfig::with_alphabet("ab").expect("NEVER");
+    assert_eq!(RadixEncoder::encode_unsigned(6, &config), "bba");
+    println!("测试异常输入.");
+    let config: RadixConfig = RadixConfig::new(10).expect("NEVER");
+    for (x, y) in [
+        ("256", "数值溢出"),
+        ("-1", "出现未预期的字符"),
+        ("12a", "出现未预期的字符"),
+        ("", "没有数字"),
+    ] {
+        println!("{x}");
+        assert_eq!(
+            RadixEncoder::decode_unsigned::<u8>(x, &config).map_err(|e| e.contains(y)),
+            Err(true)
+        );
+    }
+    assert_eq!(
+        RadixEncoder::decode_signed::<i8>("-129", &config).map_err(|e| e.contains("数值溢出")),
+        Err(true)
+    );
+    assert_eq!(
+        RadixEncoder::decode_unsigned::<u128>("340282366920938463463374607431768211456", &config)
+            .map_err(|e| e.contains("数值溢出")),
+        Err(true)
+    );
+    println!("测试string_to_u64溢出.");
+    assert_eq!(
+        RadixEncoder::string_to_u64("0xFFFF_FFFF_FFFF_FFFF"),
+        Ok(u64::MAX)
+    );
+    assert_eq!(
+        RadixEncoder::string_to_u64("0x1_0000_0000_0000_0000").map_err(|e| e.contains("数值溢出")),
+        Err(true)
+    );
+    return;
+}
@@ -0,0 +1,150 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_encoder::Base64Config;
+use iceyee_encoder::Base64Encoder;
+use iceyee_encoder::HexEncoder;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+fn data(length: usize) -> Vec<u8> {
+    return (0..length).map(|x| (x * 37 + x / 251) as u8).collect();
+}
+
+/// 每次最多读7字节.
+async fn read_all<R>(reader: R) -> Result<Vec<u8>, std::io::Error>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut reader = reader;
+    let mut output: Vec<u8> = Vec::new();
+    let mut buffer: [u8; 7] = [0; 7];
+    loop {
+        let length: usize = reader.read(&mut buffer).await?;
+        if length == 0 {
+            return Ok(output);
+        }
+        output.extend_from_slice(&buffer[..length]);
+    }
+}
+
+/// 每次最多写step字节.
+async fn write_all(input: &[u8], step: usize, config: Option<&Base64Config>) -> Vec<u8> {
+    let mut writer = match config {
+        Some(config) => Base64Encoder::encode_writer(Vec::<u8>::new(), config),
+        None => HexEncoder::encode_writer(Vec::<u8>::new()),
+    };
+    for chunk in input.chunks(step) {
+        writer.write_all(chunk).await.expect("NEVER");
+    }
+    writer.shutdown().await.expect("NEVER");
+    return writer.into_inner();
+}
+
+#[tokio::test]
+pub async fn test_base64_stream() {
+    println!("");
+    let configs = [
+        Base64Config::STANDARD,
+        Base64Config::URL_SAFE_NO_PAD,
+        Base64Config::MIME,
+        Base64Config::new().set_line_width(5),
+    ];
+    println!("测试encode_reader, encode_writer与encode_with一致.");
+    for config in configs.iter() {
+        for length in [0, 1, 2, 3, 4, 5, 57, 100, 0x3001] {
+            let input: Vec<u8> = data(length);
+            let expected: String = Base64Encoder::encode_with(&input, config);
+            let output: Vec<u8> = read_all(Base64Encoder::encode_reader(&input[..], config))
+                .await
+                .expect("NEVER");
+            assert_eq!(String::from_utf8(output).expect("NEVER"), expected);
+            for step in [1, 2, 3, 7, 0x1000] {
+                let output: Vec<u8> = write_all(&input, step, Some(config)).await;
+                assert_eq!(String::from_utf8(output).expect("NEVER"), expected);
+            }
+        }
+    }
+    println!("测试decode_reader, decode_writer与decode_with一致.");
+    for config in configs.iter() {
+        for length in [0, 1, 2, 3, 4, 5, 57, 100, 0x3001] {
+            let input: Vec<u8> = data(length);
+            let text: String = Base64Encoder::encode_with(&input, config);
+            let output: Vec<u8> = read_all(Base64Encoder::decode_reader(text.as_bytes(), config))
+                .await
+                .expect("NEVER");
+            assert_eq!(output, input);
+            let mut writer = Base64Encoder::decode_writer(Vec::<u8>::new(), config);
+            for chunk in text.as_bytes().chunks(3) {
+                writer.write_all(chunk).await.expect("NEVER");
+            }
+            writer.shutdown().await.expect("NEVER");
+            assert_eq!(writer.into_inner(), input);
+        }
+    }
+    println!("测试异常输入.");
+    for text in ["12345", "123@", "QQ==QUFB", "aGVsbG8"] {
+        let e = read_all(Base64Encoder::decode_reader(
+            text.as_bytes(),
+            &Base64Config::STANDARD,
+        ))
+        .await
+        .expect_err("NEVER");
+        println!("{}", text);
+        assert_eq!(e.kind(), std::io::ErrorKind::InvalidData);
+        assert!(Base64Encoder::decode_with(text, &Base64Config::STANDARD).is_err());
+    }
+    return;
+}
+
+#[tokio::test]
+pub async fn test_hex_stream() {
+    println!("");
+    println!("测试encode_reader, encode_writer与encode一致.");
+    for length in [0, 1, 2, 100, 0x3001] {
+        let input: Vec<u8> = data(length);
+        let expected: String = HexEncoder::encode(&input);
+        let output: Vec<u8> = read_all(HexEncoder::encode_reader(&input[..]))
+            .await
+            .expect("NEVER");
+        assert_eq!(String::from_utf8(output).expect("NEVER"), expected);
+        for step in [1, 3, 0x1000] {
+            let output: Vec<u8> = write_all(&input, step, None).await;
+            assert_eq!(String::from_utf8(output).expect("NEVER"), expected);
+        }
+        println!("测试decode_reader, decode_writer与decode一致.");
+        let output: Vec<u8> = read_all(HexEncoder::decode_reader(expected.as_bytes()))
+            .await
+            .expect("NEVER");
+        assert_eq!(output, input);
+        let mut writer = HexEncoder::decode_writer(Vec::<u8>::new());
+        for chunk in expected.as_bytes().chunks(3) {
+            writer.write_all(chunk).await.expect("NEVER");
+        }
+        writer.shutdown().await.expect("NEVER");
+        assert_eq!(writer.into_inner(), input);
+    }
+    println!("测试异常输入.");
+    for text in ["123", "12G4"] {
+        let e = read_all(HexEncoder::decode_reader(text.as_bytes()))
+            .await
+            .expect_err("NEVER");
+        println!("{}", text);
+        assert_eq!(e.kind(), std::io::ErrorKind::InvalidData);
+    }
+    return;
+}
@@ -6,6 +6,7 @@
 //
 // Use.
 
+use iceyee_encoder::UrlEncodeSet;
 use iceyee_encoder::UrlEncoder;
 
 // Enum.
@@ -54,3 +55,56 @@ pub fn test_url_encoder() {
     );
     return;
 }
+
+#[test]
+pub fn test_url_encode_component() {
+    println!("");
+    let input: &str = "a b+c/d?e&f=g#h:i@j~k'你";
+    let table = [
+        (
+            UrlEncodeSet::PathSegment,
+            "a%20b+c%2Fd%3Fe&f=g%23h:i@j~k'%E4%BD%A0",
+        ),
+        (
+            UrlEncodeSet::Query,
+            "a%20b%2Bc/d?e%26f%3Dg%23h:i@j~k'%E4%BD%A0",
+        ),
+        (
+            UrlEncodeSet::Fragment,
+            "a%20b+c/d?e&f=g%23h:i@j~k'%E4%BD%A0",
+        ),
+        (
+            UrlEncodeSet::Userinfo,
+            "a%20b+c%2Fd%3Fe&f=g%23h%3Ai%40j~k'%E4%BD%A0",
+        ),
+        (
+            UrlEncodeSet::Form,
+            "a+b%2Bc%2Fd%3Fe%26f%3Dg%23h%3Ai%40j%7Ek%27%E4%BD%A0",
+        ),
+    ];
+    println!("测试encode_component功能.");
+    for (set, y) in table {
+        println!("{:?} <encode> {y}", set);
+        let output: String = UrlEncoder::encode_component(input, set);
+        assert_eq!(output, y);
+        let plus_as_space: bool = set == UrlEncodeSet::Form;
+        assert_eq!(
+            UrlEncoder::decode_with(&output, plus_as_space).expect("NEVER"),
+            input
+        );
+    }
+    println!("测试decode_with功能.");
+    assert_eq!(
+        UrlEncoder::decode_with("a+b%20c", true).expect("NEVER"),
+        "a b c"
+    );
+    assert_eq!(
+        UrlEncoder::decode_with("a+b%20c", false).expect("NEVER"),
+        "a+b c"
+    );
+    assert_eq!(
+        UrlEncoder::decode_with("%3", false).map_err(|x| x.contains("错误的格式")),
+        Err(true)
+    );
+    return;
+}
@@ -7,6 +7,19 @@
 
 //! 创建异常和继承异常, [a], [b].
 //!
+//! 结构化的异常[IceyeeError], 由[create]和[inherit]构造, 输出的格式与[a], [b]相同.
+//!
+//! [ResultExt]和[OptionExt], 代替`.map_err(|e| iceyee_error::b!(e, ...))`,
+//! [IceyeeResultExt]代替`.map_err(|e| iceyee_error::inherit!(e, ...))`.
+//!
+//! [bail], [ensure]提前返回异常, [matches_kind]匹配[ErrorKind].
+//!
+//! [ErrorChain]把[a], [b]输出的文本解析回帧, 可以用serde序列化成json.
+//!
+//! [set_panic_hook]以[c]的格式记录panic.
+//!
+//! [MultiError]收集批量操作的多个异常.
+//!
 //! # Example
 //! ```
 //! #[test]
@@ -58,14 +71,752 @@
 
 /* Use. */
 
+use serde::Deserialize;
+use serde::Serialize;
+use std::error::Error as StdError;
+use std::panic::Location;
+use std::panic::PanicHookInfo;
+use std::sync::Arc;
+
 /* Enum. */
 
+/// 异常类型, 用于代替匹配异常信息的文本.
+///
+/// - Other, 其它.
+/// - Timeout, 超时.
+/// - Eof, 非预期的结束.
+/// - BadFormat, 非预期的格式.
+/// - NotFound, 找不到.
+///
+/// 默认[Other](ErrorKind::Other).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ErrorKind {
+    #[default]
+    Other,
+    Timeout,
+    Eof,
+    BadFormat,
+    NotFound,
+}
+
+impl From<std::io::ErrorKind> for ErrorKind {
+    fn from(value: std::io::ErrorKind) -> Self {
+        return match value {
+            std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock => Self::Timeout,
+            std::io::ErrorKind::UnexpectedEof => Self::Eof,
+            std::io::ErrorKind::InvalidData | std::io::ErrorKind::InvalidInput => Self::BadFormat,
+            std::io::ErrorKind::NotFound => Self::NotFound,
+            _ => Self::Other,
+        };
+    }
+}
+
+/// 帧的类型, 对应输出的'Create error at'和'Inherit error at'.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FrameKind {
+    Create,
+    Inherit,
+}
+
 /* Trait. */
 
+/// 为[Result]添加继承异常的接口, 记录调用者的位置.
+///
+/// 适用于`Result<T, String>`, `Result<T, IceyeeError>`, 以及任意实现了[Display](std::fmt::Display)的异常,
+/// 结果统一是`Result<T, String>`, 等效于[b].
+///
+/// [IceyeeError]转成[String]会丢失[ErrorKind]和源异常, 需要保留的话用[IceyeeResultExt].
+///
+/// # Example
+/// ```
+/// use iceyee_error::ResultExt;
+///
+/// fn read() -> Result<String, String> {
+///     return std::fs::read_to_string("/not/exists").context("reading config");
+/// }
+///
+/// let e: String = read().expect_err("NEVER");
+/// assert!(e.contains("Inherit error at"));
+/// assert!(e.ends_with(", reading config"));
+/// ```
+pub trait ResultExt<T> {
+    /// 继承异常, 同`.map_err(|e| iceyee_error::b!(e, s))`.
+    fn context<S>(self, s: S) -> Result<T, String>
+    where
+        S: ToString;
+
+    /// 继承异常, 只有出现异常时才执行f.
+    fn with_context<S, F>(self, f: F) -> Result<T, String>
+    where
+        S: ToString,
+        F: FnOnce() -> S;
+
+    /// 丢弃原来的异常, 创建新的异常, 同`.map_err(|_| iceyee_error::a!(s))`.
+    fn or_error<S>(self, s: S) -> Result<T, String>
+    where
+        S: ToString;
+}
+
+/// 为`Result<T, IceyeeError>`添加继承异常的接口, 记录调用者的位置.
+///
+/// 在原来的[IceyeeError]上追加一帧, 保留[ErrorKind]和源异常, 等效于[inherit].
+///
+/// 方法名与[ResultExt]不同, 两者可以同时导入.
+///
+/// # Example
+/// ```
+/// use iceyee_error::ErrorKind;
+/// use iceyee_error::IceyeeError;
+/// use iceyee_error::IceyeeResultExt;
+///
+/// fn read() -> Result<(), IceyeeError> {
+///     return Err(iceyee_error::create!("UnexpectedEof").set_kind(ErrorKind::Eof));
+/// }
+///
+/// let e: IceyeeError = read().inherit("reading config").expect_err("NEVER");
+/// assert_eq!(e.kind(), ErrorKind::Eof);
+/// assert_eq!(e.frames().len(), 2);
+/// assert!(e.to_string().ends_with(", reading config"));
+/// ```
+pub trait IceyeeResultExt<T> {
+    /// 继承异常, 同`.map_err(|e| iceyee_error::inherit!(e, s))`.
+    fn inherit<S>(self, s: S) -> Result<T, IceyeeError>
+    where
+        S: ToString;
+
+    /// 继承异常, 只有出现异常时才执行f.
+    fn inherit_with<S, F>(self, f: F) -> Result<T, IceyeeError>
+    where
+        S: ToString,
+        F: FnOnce() -> S;
+}
+
+/// 为[Option]添加创建异常的接口, 记录调用者的位置.
+///
+/// # Example
+/// ```
+/// use iceyee_error::OptionExt;
+///
+/// let a: Option<u8> = None;
+/// let e: String = a.or_error("not found").expect_err("NEVER");
+/// assert!(e.starts_with("Create error at"));
+/// assert!(e.ends_with(", not found"));
+/// ```
+pub trait OptionExt<T> {
+    /// 如果是[None]则创建异常, 同`.ok_or_else(|| iceyee_error::a!(s))`.
+    fn or_error<S>(self, s: S) -> Result<T, String>
+    where
+        S: ToString;
+
+    /// 如果是[None]则创建异常, 只有出现异常时才执行f.
+    fn or_else_error<S, F>(self, f: F) -> Result<T, String>
+    where
+        S: ToString,
+        F: FnOnce() -> S;
+}
+
 /* Struct. */
 
+/// 帧, 记录异常在哪里创建或继承, 以及附带的参数.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Frame {
+    pub kind: FrameKind,
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub arguments: Vec<String>,
+}
+
+/// 'Create error at file:line:column, arg1, arg2'.
+impl std::fmt::Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let kind: &str = match self.kind {
+            FrameKind::Create => "Create",
+            FrameKind::Inherit => "Inherit",
+        };
+        write!(
+            f,
+            "{} error at {}:{}:{}",
+            kind, self.file, self.line, self.column
+        )?;
+        for x in &self.arguments {
+            write!(f, ", {}", x)?;
+        }
+        return Ok(());
+    }
+}
+
+/// 解析一行'Create error at file:line:column, arg1, arg2'.
+///
+/// 参数以', '分隔, 所以参数本身包含', '时会被拆开, 但是输出的文本不变.
+///
+/// - @exception 不是'Create error at'或'Inherit error at'开头.
+/// - @exception 无效的位置.
+impl std::str::FromStr for Frame {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, s): (FrameKind, &str) = if let Some(a001) = s.strip_prefix("Create error at ") {
+            (FrameKind::Create, a001)
+        } else if let Some(a001) = s.strip_prefix("Inherit error at ") {
+            (FrameKind::Inherit, a001)
+        } else {
+            return Err(crate::a!(
+                "不是'Create error at'或'Inherit error at'开头",
+                s
+            ));
+        };
+        let mut a001 = s.splitn(2, ", ");
+        let location: &str = a001.next().expect("NEVER");
+        let arguments: Vec<String> = match a001.next() {
+            Some(a002) => a002.split(", ").map(|x| x.to_string()).collect(),
+            None => Vec::new(),
+        };
+        /* 文件名可能包含':', 比如'C:\\', 所以从右边开始拆. */
+        let mut a003 = location.rsplitn(3, ':');
+        let column: Option<u32> = a003.next().and_then(|x| x.parse::<u32>().ok());
+        let line: Option<u32> = a003.next().and_then(|x| x.parse::<u32>().ok());
+        let file: Option<&str> = a003.next().filter(|x| x.len() != 0);
+        match (file, line, column) {
+            (Some(file), Some(line), Some(column)) => {
+                return Ok(Frame {
+                    kind: kind,
+                    file: file.to_string(),
+                    line: line,
+                    column: column,
+                    arguments: arguments,
+                });
+            }
+            _ => {
+                return Err(crate::a!("无效的位置", location));
+            }
+        }
+    }
+}
+
+/// 异常链, [a], [b]输出的文本解析后的结果, 可以用serde序列化.
+///
+/// - cause, 第一帧之前的文本, 一般是被继承的std异常, 比如`iceyee_error::b!(e)`.
+/// - frames, 所有帧.
+///
+/// 不是帧的行, 当作上一帧最后一个参数的后续, 因为参数本身可能包含换行.
+///
+/// # Example
+/// ```
+/// use iceyee_error::ErrorChain;
+///
+/// let e: String = iceyee_error::b!(iceyee_error::a!("hello"), "world");
+/// let chain: ErrorChain = e.parse::<ErrorChain>().unwrap();
+/// assert_eq!(chain.frames.len(), 2);
+/// assert_eq!(chain.frames[1].arguments, vec!["world".to_string()]);
+/// assert_eq!(chain.to_string(), e);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorChain {
+    pub cause: Option<String>,
+    pub frames: Vec<Frame>,
+}
+
+/// 解析文本, 没有异常.
+impl std::str::FromStr for ErrorChain {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chain: ErrorChain = ErrorChain::default();
+        if s.len() == 0 {
+            return Ok(chain);
+        }
+        for line in s.split("\n") {
+            if let Ok(frame) = line.parse::<Frame>() {
+                chain.frames.push(frame);
+            } else if let Some(frame) = chain.frames.last_mut() {
+                match frame.arguments.last_mut() {
+                    Some(argument) => {
+                        argument.push_str("\n");
+                        argument.push_str(line);
+                    }
+                    None => frame.arguments.push(line.to_string()),
+                }
+            } else {
+                match chain.cause.as_mut() {
+                    Some(cause) => {
+                        cause.push_str("\n");
+                        cause.push_str(line);
+                    }
+                    None => chain.cause = Some(line.to_string()),
+                }
+            }
+        }
+        return Ok(chain);
+    }
+}
+
+/// 输出与[a], [b]相同的文本.
+impl std::fmt::Display for ErrorChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        if let Some(cause) = self.cause.as_ref() {
+            f.write_str(cause)?;
+            if self.frames.len() != 0 {
+                f.write_str("\n")?;
+            }
+        }
+        for (x, frame) in self.frames.iter().enumerate() {
+            if x != 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{}", frame)?;
+        }
+        return Ok(());
+    }
+}
+
+impl From<&IceyeeError> for ErrorChain {
+    fn from(value: &IceyeeError) -> Self {
+        return ErrorChain {
+            cause: None,
+            frames: value.frames().clone(),
+        };
+    }
+}
+
+/// 还原成[IceyeeError], 保留所有帧, 类型是[Other](ErrorKind::Other).
+///
+/// message是第一帧的参数; 没有帧时是cause, cause本身不输出.
+impl From<ErrorChain> for IceyeeError {
+    fn from(value: ErrorChain) -> Self {
+        let message: String = match value.frames.first() {
+            Some(frame) => frame.arguments.join(", "),
+            None => value.cause.unwrap_or_default(),
+        };
+        return IceyeeError {
+            kind: ErrorKind::Other,
+            message: message,
+            frames: value.frames,
+            source: None,
+        };
+    }
+}
+
+/// 异常.
+///
+/// 记录异常类型, 异常信息, 创建和继承时的帧, 以及可选的源异常.
+///
+/// 输出的文本与[a], [b]相同, 并且可以转成[String], 所以返回`Result<_, String>`的函数可以直接用'?'.
+///
+/// - @see [create]
+/// - @see [inherit]
+#[derive(Clone, Debug)]
+pub struct IceyeeError {
+    kind: ErrorKind,
+    message: String,
+    frames: Vec<Frame>,
+    source: Option<Arc<dyn StdError + Send + Sync>>,
+}
+
+impl IceyeeError {
+    /// 创建异常, 一般用[create]代替.
+    pub fn new(file: &str, line: u32, column: u32, arguments: Vec<String>) -> Self {
+        let message: String = arguments.join(", ");
+        return IceyeeError {
+            kind: ErrorKind::Other,
+            message: message,
+            frames: vec![Frame {
+                kind: FrameKind::Create,
+                file: file.to_string(),
+                line: line,
+                column: column,
+                arguments: arguments,
+            }],
+            source: None,
+        };
+    }
+
+    /// 由std异常创建, 参数是源异常的文本, 保留源异常, 记录调用者的位置.
+    ///
+    /// 闭包里要写成`.map_err(|e| IceyeeError::from_std(kind, e))`, 而不是传函数, 否则记录的是闭包外的位置.
+    ///
+    /// # Example
+    /// ```
+    /// use iceyee_error::ErrorKind;
+    /// use iceyee_error::IceyeeError;
+    ///
+    /// let e = std::io::Error::new(std::io::ErrorKind::TimedOut, "TimedOut");
+    /// let e: IceyeeError = IceyeeError::from_std(e.kind().into(), e);
+    /// assert_eq!(e.kind(), ErrorKind::Timeout);
+    /// assert_eq!(e.message(), "TimedOut");
+    /// ```
+    #[track_caller]
+    pub fn from_std<E>(kind: ErrorKind, source: E) -> Self
+    where
+        E: StdError + Send + Sync + 'static,
+    {
+        let location: &'static Location<'static> = Location::caller();
+        return IceyeeError::new(
+            location.file(),
+            location.line(),
+            location.column(),
+            vec![source.to_string()],
+        )
+        .set_kind(kind)
+        .set_source(source);
+    }
+
+    /// 继承异常, 一般用[inherit]代替.
+    pub fn inherit(mut self, file: &str, line: u32, column: u32, arguments: Vec<String>) -> Self {
+        self.frames.push(Frame {
+            kind: FrameKind::Inherit,
+            file: file.to_string(),
+            line: line,
+            column: column,
+            arguments: arguments,
+        });
+        return self;
+    }
+
+    pub fn set_kind(mut self, kind: ErrorKind) -> Self {
+        self.kind = kind;
+        return self;
+    }
+
+    pub fn set_source<E>(mut self, source: E) -> Self
+    where
+        E: StdError + Send + Sync + 'static,
+    {
+        self.source = Some(Arc::new(source));
+        return self;
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        return self.kind;
+    }
+
+    /// 创建异常时的参数, 以', '连接.
+    pub fn message(&self) -> &str {
+        return &self.message;
+    }
+
+    pub fn frames(&self) -> &Vec<Frame> {
+        return &self.frames;
+    }
+}
+
+impl std::fmt::Display for IceyeeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        for (x, frame) in self.frames.iter().enumerate() {
+            if x != 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{}", frame)?;
+        }
+        return Ok(());
+    }
+}
+
+impl StdError for IceyeeError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        return self
+            .source
+            .as_ref()
+            .map(|e| e.as_ref() as &(dyn StdError + 'static));
+    }
+}
+
+impl From<&IceyeeError> for IceyeeError {
+    fn from(value: &IceyeeError) -> Self {
+        return value.clone();
+    }
+}
+
+/// 兼容返回`Result<_, String>`的函数.
+impl From<IceyeeError> for String {
+    fn from(value: IceyeeError) -> Self {
+        return value.to_string();
+    }
+}
+
+/// 多个异常, 用于批量操作, 收集所有的异常而不是在第一个异常时返回.
+///
+/// 记录创建时的位置, 每个子异常保留自己的帧, 输出时缩进在第一帧下面.
+///
+/// ```text
+/// Create error at src/main.rs:10:20, 2 errors
+///     Create error at src/main.rs:12:24, hello
+///     Inherit error at src/main.rs:13:24, world
+///     Create error at src/main.rs:12:24, hello
+/// ```
+///
+/// # Example
+/// ```
+/// use iceyee_error::MultiError;
+///
+/// let mut errors: MultiError = MultiError::new();
+/// for x in 0..4 {
+///     let r: Result<u8, String> = if x % 2 == 0 {
+///         Err(iceyee_error::a!(x))
+///     } else {
+///         Ok(x)
+///     };
+///     errors.capture(r);
+/// }
+/// assert_eq!(errors.len(), 2);
+/// assert!(errors.into_result().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultiError {
+    frame: Frame,
+    errors: Vec<ErrorChain>,
+}
+
+impl MultiError {
+    /// 创建, 记录调用者的位置.
+    #[track_caller]
+    pub fn new() -> Self {
+        return MultiError {
+            frame: frame_at(FrameKind::Create, Location::caller(), Vec::new()),
+            errors: Vec::new(),
+        };
+    }
+
+    /// 加入一个异常, 可以是[String], [IceyeeError], 或者任意实现了[Display](std::fmt::Display)的异常.
+    pub fn push<E>(&mut self, e: E)
+    where
+        E: std::fmt::Display,
+    {
+        self.errors
+            .push(e.to_string().parse::<ErrorChain>().expect("NEVER"));
+        return;
+    }
+
+    /// 如果是异常则加入, 否则返回结果.
+    pub fn capture<T, E>(&mut self, r: Result<T, E>) -> Option<T>
+    where
+        E: std::fmt::Display,
+    {
+        return match r {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.push(e);
+                None
+            }
+        };
+    }
+
+    pub fn len(&self) -> usize {
+        return self.errors.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.errors.len() == 0;
+    }
+
+    pub fn errors(&self) -> &Vec<ErrorChain> {
+        return &self.errors;
+    }
+
+    /// 没有异常时返回[Ok], 否则返回自己.
+    pub fn into_result(self) -> Result<(), MultiError> {
+        if self.is_empty() {
+            return Ok(());
+        } else {
+            return Err(self);
+        }
+    }
+}
+
+impl Default for MultiError {
+    #[track_caller]
+    fn default() -> Self {
+        return MultiError::new();
+    }
+}
+
+impl<E> Extend<E> for MultiError
+where
+    E: std::fmt::Display,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        for e in iter {
+            self.push(e);
+        }
+        return;
+    }
+}
+
+impl std::fmt::Display for MultiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}, {} errors", self.frame, self.errors.len())?;
+        for chain in &self.errors {
+            for line in chain.to_string().split("\n") {
+                write!(f, "\n    {}", line)?;
+            }
+        }
+        return Ok(());
+    }
+}
+
+impl StdError for MultiError {}
+
+/// 兼容返回`Result<_, String>`的函数.
+impl From<MultiError> for String {
+    fn from(value: MultiError) -> Self {
+        return value.to_string();
+    }
+}
+
+impl<T, E> ResultExt<T> for Result<T, E>
+where
+    E: std::fmt::Display,
+{
+    #[track_caller]
+    fn context<S>(self, s: S) -> Result<T, String>
+    where
+        S: ToString,
+    {
+        let location: &'static Location<'static> = Location::caller();
+        return self.map_err(|e| {
+            let frame: Frame = frame_at(FrameKind::Inherit, location, vec![s.to_string()]);
+            format!("{}\n{}", e, frame)
+        });
+    }
+
+    #[track_caller]
+    fn with_context<S, F>(self, f: F) -> Result<T, String>
+    where
+        S: ToString,
+        F: FnOnce() -> S,
+    {
+        let location: &'static Location<'static> = Location::caller();
+        return self.map_err(|e| {
+            let frame: Frame = frame_at(FrameKind::Inherit, location, vec![f().to_string()]);
+            format!("{}\n{}", e, frame)
+        });
+    }
+
+    #[track_caller]
+    fn or_error<S>(self, s: S) -> Result<T, String>
+    where
+        S: ToString,
+    {
+        let location: &'static Location<'static> = Location::caller();
+        return self
+            .map_err(|_| frame_at(FrameKind::Create, location, vec![s.to_string()]).to_string());
+    }
+}
+
+impl<T> IceyeeResultExt<T> for Result<T, IceyeeError> {
+    #[track_caller]
+    fn inherit<S>(self, s: S) -> Result<T, IceyeeError>
+    where
+        S: ToString,
+    {
+        let location: &'static Location<'static> = Location::caller();
+        return self.map_err(|e| {
+            e.inherit(
+                location.file(),
+                location.line(),
+                location.column(),
+                vec![s.to_string()],
+            )
+        });
+    }
+
+    #[track_caller]
+    fn inherit_with<S, F>(self, f: F) -> Result<T, IceyeeError>
+    where
+        S: ToString,
+        F: FnOnce() -> S,
+    {
+        let location: &'static Location<'static> = Location::caller();
+        return self.map_err(|e| {
+            e.inherit(
+                location.file(),
+                location.line(),
+                location.column(),
+                vec![f().to_string()],
+            )
+        });
+    }
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    #[track_caller]
+    fn or_error<S>(self, s: S) -> Result<T, String>
+    where
+        S: ToString,
+    {
+        let location: &'static Location<'static> = Location::caller();
+        return self
+            .ok_or_else(|| frame_at(FrameKind::Create, location, vec![s.to_string()]).to_string());
+    }
+
+    #[track_caller]
+    fn or_else_error<S, F>(self, f: F) -> Result<T, String>
+    where
+        S: ToString,
+        F: FnOnce() -> S,
+    {
+        let location: &'static Location<'static> = Location::caller();
+        return self.ok_or_else(|| {
+            frame_at(FrameKind::Create, location, vec![f().to_string()]).to_string()
+        });
+    }
+}
+
 /* Function. */
 
+/// 设置全局的panic钩子, 代替默认的钩子.
+///
+/// 以[c]的格式记录panic的信息, 位置和堆栈, 然后交给f处理, 而不是输出到stderr.
+///
+/// f在panic的线程里执行, 不能再panic, 否则进程直接中止.
+///
+/// 如果要写到iceyee_logger的错误日志, 可以在f里面用`tokio::runtime::Handle::try_current()`,
+/// 然后spawn一个任务执行`iceyee_logger::error!(message)`.
+///
+/// # Example
+/// ```
+/// iceyee_error::set_panic_hook(|message: String| {
+///     eprintln!("{}", message);
+/// });
+/// ```
+pub fn set_panic_hook<F>(f: F)
+where
+    F: Fn(String) + Send + Sync + 'static,
+{
+    std::panic::set_hook(Box::new(move |info: &PanicHookInfo| {
+        f(panic_message(info));
+    }));
+    return;
+}
+
+/// panic的信息, 位置和堆栈, 格式同[c].
+pub fn panic_message(info: &PanicHookInfo) -> String {
+    let payload: &str = if let Some(s) = info.payload().downcast_ref::<&str>() {
+        s
+    } else if let Some(s) = info.payload().downcast_ref::<String>() {
+        s.as_str()
+    } else {
+        "Box<dyn Any>"
+    };
+    return match info.location() {
+        Some(location) => crate::c!(payload, location),
+        None => crate::c!(payload),
+    };
+}
+
+fn frame_at(kind: FrameKind, location: &Location, arguments: Vec<String>) -> Frame {
+    return Frame {
+        kind: kind,
+        file: location.file().to_string(),
+        line: location.line(),
+        column: location.column(),
+        arguments: arguments,
+    };
+}
+
 /// 创建异常.
 ///
 /// @return [String]
@@ -139,3 +890,111 @@ macro_rules! c {
         }
     };
 }
+
+/// 创建异常, 同[a], 但返回[IceyeeError].
+///
+/// @return [IceyeeError]
+#[macro_export]
+macro_rules! create {
+    ($($x:expr),* $(,)?) => {
+        $crate::IceyeeError::new(
+            file!(),
+            line!(),
+            column!(),
+            vec![$($x.to_string()),*])
+    };
+}
+
+/// 继承异常, 同[b], 但返回[IceyeeError].
+///
+/// 参数e可以是[IceyeeError]或者&[IceyeeError].
+///
+/// @return [IceyeeError]
+#[macro_export]
+macro_rules! inherit {
+    ($e:expr $(, $x:expr)* $(,)?) => {
+        $crate::IceyeeError::from($e).inherit(
+            file!(),
+            line!(),
+            column!(),
+            vec![$($x.to_string()),*])
+    };
+}
+
+/// 提前返回异常, 相当于`return Err(iceyee_error::create!(...).into())`.
+///
+/// 可以用'类型;'开头指定[ErrorKind], 返回值可以是`Result<_, IceyeeError>`或者`Result<_, String>`.
+///
+/// # Example
+/// ```
+/// fn read(length: usize) -> Result<(), String> {
+///     if length == 0 {
+///         iceyee_error::bail!(Eof; "UnexpectedEof");
+///     }
+///     if 0xFF < length {
+///         iceyee_error::bail!("大小非预期", length);
+///     }
+///     return Ok(());
+/// }
+///
+/// assert!(read(0).is_err());
+/// assert!(read(0x100).expect_err("NEVER").ends_with(", 大小非预期, 256"));
+/// ```
+#[macro_export]
+macro_rules! bail {
+    ($kind:ident; $($x:expr),* $(,)?) => {
+        return Err(::std::convert::From::from(
+            $crate::create!($($x),*).set_kind($crate::ErrorKind::$kind)))
+    };
+    ($($x:expr),* $(,)?) => {
+        return Err(::std::convert::From::from($crate::create!($($x),*)))
+    };
+}
+
+/// 如果条件不成立, 则提前返回异常, 参数同[bail].
+///
+/// # Example
+/// ```
+/// use iceyee_error::IceyeeError;
+///
+/// fn check(length: usize) -> Result<(), IceyeeError> {
+///     iceyee_error::ensure!(length <= 0xFF, BadFormat; "大小非预期", length);
+///     return Ok(());
+/// }
+///
+/// assert!(check(1).is_ok());
+/// assert!(iceyee_error::matches_kind!(check(0x100).expect_err("NEVER"), BadFormat));
+/// ```
+#[macro_export]
+macro_rules! ensure {
+    ($condition:expr, $kind:ident; $($x:expr),* $(,)?) => {
+        if !$condition {
+            $crate::bail!($kind; $($x),*);
+        }
+    };
+    ($condition:expr $(, $x:expr)* $(,)?) => {
+        if !$condition {
+            $crate::bail!($($x),*);
+        }
+    };
+}
+
+/// 匹配[IceyeeError]的[ErrorKind], 多个类型用'|'分隔.
+///
+/// @return [bool]
+///
+/// # Example
+/// ```
+/// let e = iceyee_error::create!("TimedOut").set_kind(iceyee_error::ErrorKind::Timeout);
+/// assert!(iceyee_error::matches_kind!(e, Timeout));
+/// assert!(iceyee_error::matches_kind!(&e, Timeout | Eof));
+/// assert!(!iceyee_error::matches_kind!(e, Eof));
+/// ```
+#[macro_export]
+macro_rules! matches_kind {
+    ($e:expr, $($kind:ident)|+) => {
+        matches!(
+            $crate::IceyeeError::kind(&$e),
+            $($crate::ErrorKind::$kind)|+)
+    };
+}
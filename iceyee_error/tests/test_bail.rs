@@ -0,0 +1,88 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_error::ErrorKind;
+use iceyee_error::IceyeeError;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+fn f001(x: usize) -> Result<usize, IceyeeError> {
+    if x == 0 {
+        iceyee_error::bail!("zero");
+    }
+    iceyee_error::ensure!(x != 1, NotFound; "one", x);
+    iceyee_error::ensure!(x != 2);
+    iceyee_error::ensure!(x != 3, "three");
+    return Ok(x);
+}
+
+fn f002(x: usize) -> Result<usize, String> {
+    iceyee_error::ensure!(x != 0, Timeout; "zero");
+    let message: &str = "four";
+    iceyee_error::ensure!(x != 4, message);
+    return Ok(f001(x)?);
+}
+
+#[test]
+pub fn test_bail() {
+    println!("");
+    println!("测试bail!, ensure!.");
+    assert_eq!(f001(5).expect("NEVER"), 5);
+    let e: IceyeeError = f001(0).expect_err("NEVER");
+    println!("{}", e);
+    assert_eq!(e.kind(), ErrorKind::Other);
+    assert_eq!(e.message(), "zero");
+    let e: IceyeeError = f001(1).expect_err("NEVER");
+    println!("{}", e);
+    assert_eq!(e.kind(), ErrorKind::NotFound);
+    assert_eq!(e.message(), "one, 1");
+    let e: IceyeeError = f001(2).expect_err("NEVER");
+    println!("{}", e);
+    assert_eq!(e.message(), "");
+    assert!(!e.to_string().contains(", "));
+    assert_eq!(f001(3).expect_err("NEVER").message(), "three");
+    println!("测试返回String.");
+    let e: String = f002(0).expect_err("NEVER");
+    println!("{}", e);
+    assert!(e.starts_with("Create error at iceyee_error/tests/test_bail.rs:"));
+    assert!(e.ends_with(", zero"));
+    assert!(f002(1).expect_err("NEVER").ends_with(", one, 1"));
+    assert!(f002(4).expect_err("NEVER").ends_with(", four"));
+    println!("测试matches_kind!.");
+    let e: IceyeeError = f001(1).expect_err("NEVER");
+    assert!(iceyee_error::matches_kind!(e, NotFound));
+    assert!(iceyee_error::matches_kind!(&e, Timeout | NotFound));
+    assert!(!iceyee_error::matches_kind!(
+        e,
+        Timeout | Eof | BadFormat | Other
+    ));
+    println!("测试std::io::ErrorKind.");
+    assert_eq!(
+        ErrorKind::from(std::io::ErrorKind::TimedOut),
+        ErrorKind::Timeout
+    );
+    assert_eq!(
+        ErrorKind::from(std::io::ErrorKind::UnexpectedEof),
+        ErrorKind::Eof
+    );
+    assert_eq!(
+        ErrorKind::from(std::io::ErrorKind::NotFound),
+        ErrorKind::NotFound
+    );
+    assert_eq!(
+        ErrorKind::from(std::io::ErrorKind::PermissionDenied),
+        ErrorKind::Other
+    );
+    return;
+}
@@ -0,0 +1,145 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_error::ErrorChain;
+use iceyee_error::Frame;
+use iceyee_error::FrameKind;
+use iceyee_error::IceyeeError;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_frame() {
+    println!("");
+    println!("测试解析帧.");
+    let table = [
+        (
+            "Create error at iceyee_error/tests/test_a.rs:20:16",
+            FrameKind::Create,
+            "iceyee_error/tests/test_a.rs",
+            20,
+            16,
+            vec![],
+        ),
+        (
+            "Inherit error at iceyee_error/tests/test_a.rs:23:16, how, are, you",
+            FrameKind::Inherit,
+            "iceyee_error/tests/test_a.rs",
+            23,
+            16,
+            vec!["how", "are", "you"],
+        ),
+        (
+            "Create error at C:\\iceyee\\src\\lib.rs:1:2, hello world",
+            FrameKind::Create,
+            "C:\\iceyee\\src\\lib.rs",
+            1,
+            2,
+            vec!["hello world"],
+        ),
+    ];
+    for (s, kind, file, line, column, arguments) in table {
+        println!("{}", s);
+        let frame: Frame = s.parse::<Frame>().expect("NEVER");
+        assert_eq!(frame.kind, kind);
+        assert_eq!(frame.file, file);
+        assert_eq!(frame.line, line);
+        assert_eq!(frame.column, column);
+        assert_eq!(frame.arguments, arguments);
+        assert_eq!(frame.to_string(), s);
+    }
+    println!("测试解析帧异常输入.");
+    for s in [
+        "",
+        "hello world",
+        "Create error at ",
+        "Create error at lib.rs:1",
+        "Create error at :1:2",
+        "Inherit error at lib.rs:x:2, hello",
+    ] {
+        println!("{:?}", s);
+        assert!(s.parse::<Frame>().is_err());
+    }
+    return;
+}
+
+#[test]
+pub fn test_error_chain() {
+    println!("");
+    println!("测试解析a!, b!.");
+    let a001: String = iceyee_error::a!("hello", "world");
+    let a002: String = iceyee_error::b!(&a001, "how", "are", "you");
+    let a003: String = iceyee_error::b!(&a002);
+    let chain: ErrorChain = a003.parse::<ErrorChain>().expect("NEVER");
+    println!("{:#?}", chain);
+    assert!(chain.cause.is_none());
+    assert_eq!(chain.frames.len(), 3);
+    assert_eq!(chain.frames[0].kind, FrameKind::Create);
+    assert_eq!(chain.frames[0].arguments, vec!["hello", "world"]);
+    assert_eq!(chain.frames[1].kind, FrameKind::Inherit);
+    assert_eq!(chain.frames[1].arguments, vec!["how", "are", "you"]);
+    assert_eq!(chain.frames[2].arguments.len(), 0);
+    assert_eq!(chain.to_string(), a003);
+    println!("测试继承std异常.");
+    let a004 = "x".parse::<u8>().expect_err("NEVER");
+    let a005: String = iceyee_error::b!(a004, "parse");
+    let chain: ErrorChain = a005.parse::<ErrorChain>().expect("NEVER");
+    assert_eq!(
+        chain.cause,
+        Some("invalid digit found in string".to_string())
+    );
+    assert_eq!(chain.frames.len(), 1);
+    assert_eq!(chain.to_string(), a005);
+    println!("测试参数包含换行.");
+    let a006: String = iceyee_error::b!(iceyee_error::a!("line1\nline2"), "line3");
+    let chain: ErrorChain = a006.parse::<ErrorChain>().expect("NEVER");
+    assert_eq!(chain.frames.len(), 2);
+    assert_eq!(chain.frames[0].arguments, vec!["line1\nline2"]);
+    assert_eq!(chain.to_string(), a006);
+    assert_eq!("".parse::<ErrorChain>(), Ok(ErrorChain::default()));
+    println!("测试IceyeeError.");
+    let a007: IceyeeError = iceyee_error::inherit!(iceyee_error::create!("hello"), "world");
+    let chain: ErrorChain = ErrorChain::from(&a007);
+    assert_eq!(chain.to_string(), a007.to_string());
+    assert_eq!(a007.to_string().parse::<ErrorChain>(), Ok(chain.clone()));
+    println!("测试还原成IceyeeError, 帧保持不变.");
+    let a008: IceyeeError = IceyeeError::from(chain);
+    assert_eq!(a008.to_string(), a007.to_string());
+    assert_eq!(a008.frames(), a007.frames());
+    assert_eq!(a008.message(), "hello");
+    let a009: String = iceyee_error::a!("hello", "world");
+    let a009: IceyeeError =
+        iceyee_error::inherit!(a009.parse::<ErrorChain>().expect("NEVER"), "again");
+    assert_eq!(a009.frames().len(), 2);
+    assert_eq!(a009.frames()[1].kind, FrameKind::Inherit);
+    assert_eq!(a009.message(), "hello, world");
+    return;
+}
+
+#[test]
+pub fn test_error_chain_json() {
+    println!("");
+    println!("测试序列化json.");
+    let a001: String = iceyee_error::b!(iceyee_error::a!("hello"), "world");
+    let chain: ErrorChain = a001.parse::<ErrorChain>().expect("NEVER");
+    let json: String = serde_json::to_string(&chain).expect("NEVER");
+    println!("{}", json);
+    assert!(json.starts_with("{\"cause\":null,\"frames\":[{\"kind\":\"Create\",\"file\":\"iceyee_error/tests/test_error_chain.rs\","));
+    assert!(json.contains("\"arguments\":[\"hello\"]"));
+    assert!(json.contains("{\"kind\":\"Inherit\""));
+    let a002: ErrorChain = serde_json::from_str(&json).expect("NEVER");
+    assert_eq!(a002, chain);
+    assert_eq!(a002.to_string(), a001);
+    return;
+}
@@ -0,0 +1,80 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_error::ErrorKind;
+use iceyee_error::FrameKind;
+use iceyee_error::IceyeeError;
+use std::error::Error;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_iceyee_error() {
+    println!("");
+    println!("测试输出与a!, b!相同.");
+    let a001: IceyeeError = iceyee_error::create!("hello", "world");
+    let a002: String = iceyee_error::a!("hello", "world");
+    println!("{}", a001);
+    assert_eq!(
+        a001.to_string().split_once(":").expect("NEVER").0,
+        a002.split_once(":").expect("NEVER").0
+    );
+    assert_eq!(
+        a001.to_string().split_once(", ").expect("NEVER").1,
+        a002.split_once(", ").expect("NEVER").1
+    );
+    let a003: IceyeeError = iceyee_error::inherit!(&a001, "how", "are", "you");
+    let a004: IceyeeError = iceyee_error::inherit!(a003.clone());
+    println!("{}", a004);
+    let a005: String = a004.to_string();
+    let lines: Vec<&str> = a005.split("\n").collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("Create error at iceyee_error/tests/test_iceyee_error.rs:"));
+    assert!(lines[1].starts_with("Inherit error at iceyee_error/tests/test_iceyee_error.rs:"));
+    assert!(lines[1].ends_with(", how, are, you"));
+    assert_eq!(a004.frames().len(), 3);
+    assert_eq!(a004.frames()[0].kind, FrameKind::Create);
+    assert_eq!(a004.frames()[2].kind, FrameKind::Inherit);
+    assert_eq!(a004.frames()[2].arguments.len(), 0);
+    println!("测试异常信息, 类型, 源异常.");
+    assert_eq!(a004.message(), "hello, world");
+    assert_eq!(a004.kind(), ErrorKind::Other);
+    assert!(a004.source().is_none());
+    let a006 = std::io::Error::other("io");
+    let a006: IceyeeError = iceyee_error::create!(a006).set_source(a006);
+    assert_eq!(a006.message(), "io");
+    assert_eq!(a006.source().map(|e| e.to_string()), Some("io".to_string()));
+    println!("测试由std异常创建.");
+    let a008 = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "eof");
+    let (a008, line): (IceyeeError, u32) =
+        (IceyeeError::from_std(a008.kind().into(), a008), line!());
+    assert_eq!(a008.kind(), ErrorKind::Eof);
+    assert_eq!(a008.message(), "eof");
+    assert_eq!(
+        a008.source().map(|e| e.to_string()),
+        Some("eof".to_string())
+    );
+    assert_eq!(
+        a008.frames()[0].file,
+        "iceyee_error/tests/test_iceyee_error.rs"
+    );
+    assert_eq!(a008.frames()[0].line, line);
+    println!("测试转成String.");
+    let a007 = || -> Result<(), String> {
+        Err(iceyee_error::create!("hello"))?;
+        return Ok(());
+    };
+    assert!(a007().expect_err("NEVER").ends_with(", hello"));
+    return;
+}
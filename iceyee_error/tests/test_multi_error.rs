@@ -0,0 +1,80 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_error::MultiError;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+fn f001(x: usize) -> Result<usize, String> {
+    if x.is_multiple_of(3) {
+        return Err(iceyee_error::b!(iceyee_error::a!("hello", x), "world"));
+    }
+    return Ok(x);
+}
+
+fn f002(length: usize) -> Result<(), String> {
+    let mut errors: MultiError = MultiError::new();
+    for x in 1..length {
+        errors.capture(f001(x));
+    }
+    errors.into_result()?;
+    return Ok(());
+}
+
+#[test]
+pub fn test_multi_error() {
+    println!("");
+    println!("没有异常.");
+    let errors: MultiError = MultiError::new();
+    assert!(errors.is_empty());
+    assert!(errors.into_result().is_ok());
+    assert!(f002(3).is_ok());
+    println!("");
+    println!("收集异常, 3和6.");
+    let mut errors: MultiError = MultiError::new();
+    let mut values: Vec<usize> = Vec::new();
+    for x in 1..8 {
+        if let Some(x) = errors.capture(f001(x)) {
+            values.push(x);
+        }
+    }
+    println!("{}", errors);
+    assert_eq!(values, vec![1, 2, 4, 5, 7]);
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors.errors()[0].frames.len(), 2);
+    assert_eq!(
+        errors.errors()[1].frames[0].arguments,
+        vec!["hello".to_string(), "6".to_string()]
+    );
+    let s: String = errors.to_string();
+    let lines: Vec<&str> = s.split("\n").collect();
+    assert_eq!(lines.len(), 5);
+    assert!(lines[0].starts_with("Create error at iceyee_error/tests/test_multi_error.rs:"));
+    assert!(lines[0].ends_with(", 2 errors"));
+    assert!(lines[1].starts_with("    Create error at "));
+    assert!(lines[1].ends_with(", hello, 3"));
+    assert!(lines[2].starts_with("    Inherit error at "));
+    assert!(lines[2].ends_with(", world"));
+    assert!(lines[3].ends_with(", hello, 6"));
+    println!("");
+    println!("Extend, 以及转成String.");
+    let mut errors: MultiError = MultiError::new();
+    errors.push(iceyee_error::create!("io"));
+    errors.extend(vec![iceyee_error::a!("a"), iceyee_error::a!("b")]);
+    assert_eq!(errors.len(), 3);
+    let e: String = f002(7).expect_err("NEVER");
+    println!("{}", e);
+    assert!(e.split("\n").next().expect("NEVER").ends_with(", 2 errors"));
+    return;
+}
@@ -0,0 +1,50 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_panic_hook() {
+    println!("");
+    println!("测试panic钩子.");
+    let messages: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let messages_clone = messages.clone();
+    iceyee_error::set_panic_hook(move |message: String| {
+        messages_clone.lock().expect("NEVER").push(message);
+    });
+    let a001 = std::thread::spawn(|| {
+        panic!("bad pattern");
+    })
+    .join();
+    let a002 = std::thread::spawn(|| {
+        let x: Option<u8> = None;
+        x.expect("NEVER");
+    })
+    .join();
+    let _ = std::panic::take_hook();
+    assert!(a001.is_err());
+    assert!(a002.is_err());
+    let messages = messages.lock().expect("NEVER");
+    for x in messages.iter() {
+        println!("{}", x);
+    }
+    assert_eq!(messages.len(), 2);
+    assert!(messages[0].starts_with("error: bad pattern iceyee_error/tests/test_panic_hook.rs:"));
+    assert!(messages[1].starts_with("error: NEVER iceyee_error/tests/test_panic_hook.rs:"));
+    assert!(messages[0].contains("\n"));
+    return;
+}
@@ -0,0 +1,107 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_error::ErrorKind;
+use iceyee_error::FrameKind;
+use iceyee_error::IceyeeError;
+use iceyee_error::IceyeeResultExt;
+use iceyee_error::OptionExt;
+use iceyee_error::ResultExt;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_result_ext() {
+    println!("");
+    println!("测试String异常.");
+    let a001: Result<(), String> = Err(iceyee_error::a!("hello"));
+    let a002: String = a001.clone().context("world").expect_err("NEVER");
+    println!("{}", a002);
+    let line: u32 = line!() - 2;
+    let lines: Vec<&str> = a002.split("\n").collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], a001.clone().expect_err("NEVER"));
+    assert_eq!(
+        lines[1],
+        format!(
+            "Inherit error at iceyee_error/tests/test_result_ext.rs:{}:37, world",
+            line
+        )
+    );
+    println!("测试with_context, 只有出现异常时才执行.");
+    let a003: Result<u8, String> = Ok(1);
+    assert_eq!(a003.with_context(|| -> String { panic!("NEVER") }), Ok(1));
+    let a004: String = a001
+        .clone()
+        .with_context(|| format!("{}-{}", "a", "b"))
+        .expect_err("NEVER");
+    assert!(a004.ends_with(", a-b"));
+    println!("测试std异常.");
+    let a005: String = "x".parse::<u8>().context("parse").expect_err("NEVER");
+    println!("{}", a005);
+    assert!(a005.starts_with("invalid digit found in string\nInherit error at "));
+    assert!(a005.ends_with(", parse"));
+    println!("测试IceyeeError.");
+    let a006: Result<(), IceyeeError> = Err(iceyee_error::create!("hello"));
+    let a007: String = a006.context("world").expect_err("NEVER");
+    assert!(a007.starts_with("Create error at "));
+    assert!(a007.contains(", hello\nInherit error at "));
+    println!("测试IceyeeResultExt, 保留类型和源异常.");
+    let source: std::io::Error = std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out");
+    let a011: Result<(), IceyeeError> = Err(iceyee_error::create!("hello")
+        .set_kind(ErrorKind::Timeout)
+        .set_source(source));
+    let a012: IceyeeError = a011.inherit("world").expect_err("NEVER");
+    let line: u32 = line!() - 1;
+    println!("{}", a012);
+    assert_eq!(a012.kind(), ErrorKind::Timeout);
+    assert!(std::error::Error::source(&a012).is_some());
+    assert_eq!(a012.frames().len(), 2);
+    assert_eq!(a012.frames()[1].kind, FrameKind::Inherit);
+    assert_eq!(a012.frames()[1].line, line);
+    assert_eq!(a012.frames()[1].arguments, vec!["world".to_string()]);
+    let a013: Result<u8, IceyeeError> = Ok(1);
+    assert_eq!(
+        a013.inherit_with(|| -> String { panic!("NEVER") })
+            .expect("NEVER"),
+        1
+    );
+    let a014: Result<(), IceyeeError> = Err(a012);
+    let a015: IceyeeError = a014
+        .inherit_with(|| format!("{}-{}", "a", "b"))
+        .expect_err("NEVER");
+    assert_eq!(a015.kind(), ErrorKind::Timeout);
+    assert_eq!(a015.frames().len(), 3);
+    assert!(a015.to_string().ends_with(", a-b"));
+    println!("测试or_error.");
+    let a008: String = "x".parse::<u8>().or_error("bad").expect_err("NEVER");
+    assert!(a008.starts_with("Create error at iceyee_error/tests/test_result_ext.rs:"));
+    assert!(a008.ends_with(", bad"));
+    assert!(!a008.contains("\n"));
+    println!("测试Option.");
+    let a009: Option<u8> = None;
+    let a010: String = a009.or_error("none").expect_err("NEVER");
+    assert!(a010.starts_with("Create error at iceyee_error/tests/test_result_ext.rs:"));
+    assert!(a010.ends_with(", none"));
+    assert_eq!(
+        Some(1).or_else_error(|| -> String { panic!("NEVER") }),
+        Ok(1)
+    );
+    assert!(
+        a009.or_else_error(|| "none")
+            .expect_err("NEVER")
+            .ends_with(", none")
+    );
+    return;
+}
@@ -0,0 +1,807 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+
+//! 校验和与摘要.
+//!
+//! 所有算法都实现[Digest], 可以分多次[Digest::update], 最后[Digest::finalize].
+//! [Hmac]可以使用任意一种[Digest].
+
+/* Use. */
+
+use iceyee_encoder::Base64Encoder;
+use iceyee_encoder::HexEncoder;
+
+/* Enum. */
+
+/* Trait. */
+
+/// 摘要算法.
+pub trait Digest: Sized {
+    /// 结果的字节数.
+    const OUTPUT_LENGTH: usize;
+
+    /// 分组的字节数, 用于[Hmac].
+    const BLOCK_LENGTH: usize;
+
+    /// 初始状态.
+    fn new() -> Self;
+
+    /// 追加数据, 可以调用多次.
+    fn update(&mut self, data: &[u8]);
+
+    /// 结束并输出结果, 长度是[Digest::OUTPUT_LENGTH].
+    fn finalize(self) -> Vec<u8>;
+
+    /// 一次性计算.
+    fn digest(data: &[u8]) -> Vec<u8> {
+        let mut digest: Self = Self::new();
+        digest.update(data);
+        return digest.finalize();
+    }
+
+    /// 结束并输出小写的十六进制.
+    fn finalize_hex(self) -> String {
+        return HexEncoder::encode(&self.finalize()).to_ascii_lowercase();
+    }
+
+    /// 结束并输出Base64.
+    fn finalize_base64(self) -> String {
+        return Base64Encoder::encode(&self.finalize());
+    }
+}
+
+/* Struct. */
+
+/// CRC-32, 多项式0x04C11DB7, 即zip, png, gzip使用的算法.
+///
+/// 结果是大端的4个字节, 也可以用[Crc32::value]得到整数.
+#[derive(Debug, Clone)]
+pub struct Crc32 {
+    crc: u32,
+}
+
+impl Crc32 {
+    /// 当前的值.
+    pub fn value(&self) -> u32 {
+        return !self.crc;
+    }
+}
+
+impl Digest for Crc32 {
+    const OUTPUT_LENGTH: usize = 4;
+    const BLOCK_LENGTH: usize = 1;
+
+    fn new() -> Self {
+        return Self { crc: 0xFFFFFFFF };
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let mut crc: u32 = self.crc;
+        for x in data {
+            crc = CRC32_TABLE[((crc ^ *x as u32) & 0xFF) as usize] ^ (crc >> 8);
+        }
+        self.crc = crc;
+        return;
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        return self.value().to_be_bytes().to_vec();
+    }
+}
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// Adler-32, zlib使用的校验和.
+///
+/// 结果是大端的4个字节, 也可以用[Adler32::value]得到整数.
+#[derive(Debug, Clone)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32 {
+    /// 当前的值.
+    pub fn value(&self) -> u32 {
+        return (self.b << 16) | self.a;
+    }
+}
+
+impl Digest for Adler32 {
+    const OUTPUT_LENGTH: usize = 4;
+    const BLOCK_LENGTH: usize = 1;
+
+    fn new() -> Self {
+        return Self { a: 1, b: 0 };
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        const MOD: u32 = 65521;
+        // 5552是保证b不溢出u32的最大长度.
+        for chunk in data.chunks(5552) {
+            for x in chunk {
+                self.a += *x as u32;
+                self.b += self.a;
+            }
+            self.a %= MOD;
+            self.b %= MOD;
+        }
+        return;
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        return self.value().to_be_bytes().to_vec();
+    }
+}
+
+impl Default for Adler32 {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// 分组的缓冲, N是分组的字节数, MD5, SHA-1, SHA-256是64, SHA-384, SHA-512是128.
+#[derive(Debug, Clone)]
+struct Block<const N: usize> {
+    buffer: [u8; N],
+    length: usize,
+    total: u128,
+}
+
+impl<const N: usize> Block<N> {
+    fn new() -> Self {
+        return Self {
+            buffer: [0; N],
+            length: 0,
+            total: 0,
+        };
+    }
+
+    /// 追加数据, 每凑满一组调用一次compress.
+    fn update<F>(&mut self, data: &[u8], mut compress: F)
+    where
+        F: FnMut(&[u8; N]),
+    {
+        self.total = self.total.wrapping_add(data.len() as u128);
+        let mut data: &[u8] = data;
+        if 0 < self.length {
+            let n: usize = (N - self.length).min(data.len());
+            self.buffer[self.length..self.length + n].copy_from_slice(&data[..n]);
+            self.length += n;
+            data = &data[n..];
+            if self.length < N {
+                return;
+            }
+            compress(&self.buffer);
+            self.length = 0;
+        }
+        let mut chunks = data.chunks_exact(N);
+        for chunk in &mut chunks {
+            compress(chunk.try_into().expect("NEVER"));
+        }
+        let rest: &[u8] = chunks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.length = rest.len();
+        return;
+    }
+
+    /// 补位, 0x80, 若干0, 最后N/8字节是总位数.
+    fn finish<F>(&mut self, big_endian: bool, mut compress: F)
+    where
+        F: FnMut(&[u8; N]),
+    {
+        let size: usize = N / 8;
+        let bits: u128 = self.total.wrapping_mul(8);
+        self.buffer[self.length] = 0x80;
+        self.buffer[self.length + 1..].fill(0);
+        if N - size < self.length + 1 {
+            compress(&self.buffer);
+            self.buffer.fill(0);
+        }
+        if big_endian {
+            self.buffer[N - size..].copy_from_slice(&bits.to_be_bytes()[16 - size..]);
+        } else {
+            self.buffer[N - size..].copy_from_slice(&bits.to_le_bytes()[..size]);
+        }
+        compress(&self.buffer);
+        self.length = 0;
+        return;
+    }
+}
+
+/// MD5, RFC 1321.
+///
+/// 仅用于校验, 不要用于安全相关的场景.
+#[derive(Debug, Clone)]
+pub struct Md5 {
+    state: [u32; 4],
+    block: Block<64>,
+}
+
+impl Md5 {
+    fn compress(state: &mut [u32; 4], block: &[u8; 64]) {
+        const S: [u32; 64] = [
+            7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20,
+            5, 9, 14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23,
+            6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+        ];
+        let mut m: [u32; 16] = [0; 16];
+        for x in 0..16 {
+            m[x] = u32::from_le_bytes(block[x * 4..x * 4 + 4].try_into().expect("NEVER"));
+        }
+        let [mut a, mut b, mut c, mut d] = *state;
+        for x in 0..64 {
+            let (f, g): (u32, usize) = match x / 16 {
+                0 => ((b & c) | (!b & d), x),
+                1 => ((d & b) | (!d & c), (5 * x + 1) % 16),
+                2 => (b ^ c ^ d, (3 * x + 5) % 16),
+                _ => (c ^ (b | !d), (7 * x) % 16),
+            };
+            let f: u32 = f
+                .wrapping_add(a)
+                .wrapping_add(MD5_TABLE[x])
+                .wrapping_add(m[g]);
+            a = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(f.rotate_left(S[x]));
+        }
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+        return;
+    }
+}
+
+impl Digest for Md5 {
+    const OUTPUT_LENGTH: usize = 16;
+    const BLOCK_LENGTH: usize = 64;
+
+    fn new() -> Self {
+        return Self {
+            state: [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476],
+            block: Block::new(),
+        };
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let state: &mut [u32; 4] = &mut self.state;
+        self.block.update(data, |x| Self::compress(state, x));
+        return;
+    }
+
+    fn finalize(mut self) -> Vec<u8> {
+        let state: &mut [u32; 4] = &mut self.state;
+        self.block.finish(false, |x| Self::compress(state, x));
+        return self.state.iter().flat_map(|x| x.to_le_bytes()).collect();
+    }
+}
+
+impl Default for Md5 {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// SHA-1, RFC 3174.
+///
+/// 仅用于校验和兼容旧协议, 不要用于新的安全相关的场景.
+#[derive(Debug, Clone)]
+pub struct Sha1 {
+    state: [u32; 5],
+    block: Block<64>,
+}
+
+impl Sha1 {
+    fn compress(state: &mut [u32; 5], block: &[u8; 64]) {
+        let mut w: [u32; 80] = [0; 80];
+        for x in 0..16 {
+            w[x] = u32::from_be_bytes(block[x * 4..x * 4 + 4].try_into().expect("NEVER"));
+        }
+        for x in 16..80 {
+            w[x] = (w[x - 3] ^ w[x - 8] ^ w[x - 14] ^ w[x - 16]).rotate_left(1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e] = *state;
+        for (x, w) in w.iter().enumerate() {
+            let (f, k): (u32, u32) = match x / 20 {
+                0 => ((b & c) | (!b & d), 0x5A827999),
+                1 => (b ^ c ^ d, 0x6ED9EBA1),
+                2 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+                _ => (b ^ c ^ d, 0xCA62C1D6),
+            };
+            let t: u32 = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*w);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = t;
+        }
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+        state[4] = state[4].wrapping_add(e);
+        return;
+    }
+}
+
+impl Digest for Sha1 {
+    const OUTPUT_LENGTH: usize = 20;
+    const BLOCK_LENGTH: usize = 64;
+
+    fn new() -> Self {
+        return Self {
+            state: [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0],
+            block: Block::new(),
+        };
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let state: &mut [u32; 5] = &mut self.state;
+        self.block.update(data, |x| Self::compress(state, x));
+        return;
+    }
+
+    fn finalize(mut self) -> Vec<u8> {
+        let state: &mut [u32; 5] = &mut self.state;
+        self.block.finish(true, |x| Self::compress(state, x));
+        return self.state.iter().flat_map(|x| x.to_be_bytes()).collect();
+    }
+}
+
+impl Default for Sha1 {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// SHA-256, FIPS 180-4.
+#[derive(Debug, Clone)]
+pub struct Sha256 {
+    state: [u32; 8],
+    block: Block<64>,
+}
+
+impl Sha256 {
+    fn compress(state: &mut [u32; 8], block: &[u8; 64]) {
+        let mut w: [u32; 64] = [0; 64];
+        for x in 0..16 {
+            w[x] = u32::from_be_bytes(block[x * 4..x * 4 + 4].try_into().expect("NEVER"));
+        }
+        for x in 16..64 {
+            let s0: u32 = w[x - 15].rotate_right(7) ^ w[x - 15].rotate_right(18) ^ (w[x - 15] >> 3);
+            let s1: u32 = w[x - 2].rotate_right(17) ^ w[x - 2].rotate_right(19) ^ (w[x - 2] >> 10);
+            w[x] = w[x - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[x - 7])
+                .wrapping_add(s1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+        for x in 0..64 {
+            let s1: u32 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch: u32 = (e & f) ^ (!e & g);
+            let t1: u32 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(SHA256_TABLE[x])
+                .wrapping_add(w[x]);
+            let s0: u32 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj: u32 = (a & b) ^ (a & c) ^ (b & c);
+            let t2: u32 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+        for (x, y) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *x = x.wrapping_add(y);
+        }
+        return;
+    }
+}
+
+impl Digest for Sha256 {
+    const OUTPUT_LENGTH: usize = 32;
+    const BLOCK_LENGTH: usize = 64;
+
+    fn new() -> Self {
+        return Self {
+            state: [
+                0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB,
+                0x5BE0CD19,
+            ],
+            block: Block::new(),
+        };
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let state: &mut [u32; 8] = &mut self.state;
+        self.block.update(data, |x| Self::compress(state, x));
+        return;
+    }
+
+    fn finalize(mut self) -> Vec<u8> {
+        let state: &mut [u32; 8] = &mut self.state;
+        self.block.finish(true, |x| Self::compress(state, x));
+        return self.state.iter().flat_map(|x| x.to_be_bytes()).collect();
+    }
+}
+
+impl Default for Sha256 {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// SHA-512, FIPS 180-4.
+#[derive(Debug, Clone)]
+pub struct Sha512 {
+    state: [u64; 8],
+    block: Block<128>,
+}
+
+impl Sha512 {
+    fn compress(state: &mut [u64; 8], block: &[u8; 128]) {
+        let mut w: [u64; 80] = [0; 80];
+        for x in 0..16 {
+            w[x] = u64::from_be_bytes(block[x * 8..x * 8 + 8].try_into().expect("NEVER"));
+        }
+        for x in 16..80 {
+            let s0: u64 = w[x - 15].rotate_right(1) ^ w[x - 15].rotate_right(8) ^ (w[x - 15] >> 7);
+            let s1: u64 = w[x - 2].rotate_right(19) ^ w[x - 2].rotate_right(61) ^ (w[x - 2] >> 6);
+            w[x] = w[x - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[x - 7])
+                .wrapping_add(s1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+        for x in 0..80 {
+            let s1: u64 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+            let ch: u64 = (e & f) ^ (!e & g);
+            let t1: u64 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(SHA512_TABLE[x])
+                .wrapping_add(w[x]);
+            let s0: u64 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+            let maj: u64 = (a & b) ^ (a & c) ^ (b & c);
+            let t2: u64 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+        for (x, y) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *x = x.wrapping_add(y);
+        }
+        return;
+    }
+
+    fn with_state(state: [u64; 8]) -> Self {
+        return Self {
+            state: state,
+            block: Block::new(),
+        };
+    }
+
+    fn finish(mut self) -> [u64; 8] {
+        let state: &mut [u64; 8] = &mut self.state;
+        self.block.finish(true, |x| Self::compress(state, x));
+        return self.state;
+    }
+}
+
+impl Digest for Sha512 {
+    const OUTPUT_LENGTH: usize = 64;
+    const BLOCK_LENGTH: usize = 128;
+
+    fn new() -> Self {
+        return Self::with_state([
+            0x6A09E667F3BCC908,
+            0xBB67AE8584CAA73B,
+            0x3C6EF372FE94F82B,
+            0xA54FF53A5F1D36F1,
+            0x510E527FADE682D1,
+            0x9B05688C2B3E6C1F,
+            0x1F83D9ABFB41BD6B,
+            0x5BE0CD19137E2179,
+        ]);
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let state: &mut [u64; 8] = &mut self.state;
+        self.block.update(data, |x| Self::compress(state, x));
+        return;
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        return self.finish().iter().flat_map(|x| x.to_be_bytes()).collect();
+    }
+}
+
+impl Default for Sha512 {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// SHA-384, FIPS 180-4, 与SHA-512的初始状态不同, 结果截取前48字节.
+#[derive(Debug, Clone)]
+pub struct Sha384 {
+    inner: Sha512,
+}
+
+impl Digest for Sha384 {
+    const OUTPUT_LENGTH: usize = 48;
+    const BLOCK_LENGTH: usize = 128;
+
+    fn new() -> Self {
+        return Self {
+            inner: Sha512::with_state([
+                0xCBBB9D5DC1059ED8,
+                0x629A292A367CD507,
+                0x9159015A3070DD17,
+                0x152FECD8F70E5939,
+                0x67332667FFC00B31,
+                0x8EB44A8768581511,
+                0xDB0C2E0D64F98FA7,
+                0x47B5481DBEFA4FA4,
+            ]),
+        };
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+        return;
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        return self.inner.finish()[..6]
+            .iter()
+            .flat_map(|x| x.to_be_bytes())
+            .collect();
+    }
+}
+
+impl Default for Sha384 {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// HMAC, RFC 2104.
+///
+/// ```
+/// use iceyee_hash::Digest;
+/// use iceyee_hash::Hmac;
+/// use iceyee_hash::Sha256;
+///
+/// let mut mac: Hmac<Sha256> = Hmac::new(b"key");
+/// mac.update(b"The quick brown fox ");
+/// mac.update(b"jumps over the lazy dog");
+/// assert_eq!(
+///     mac.finalize_hex(),
+///     "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Hmac<D: Digest> {
+    inner: D,
+    outer: D,
+}
+
+impl<D: Digest> Hmac<D> {
+    /// 密钥可以是任意长度, 超过分组长度的先做一次摘要.
+    pub fn new(key: &[u8]) -> Self {
+        let mut block: Vec<u8> = if D::BLOCK_LENGTH < key.len() {
+            D::digest(key)
+        } else {
+            key.to_vec()
+        };
+        block.resize(D::BLOCK_LENGTH, 0);
+        let mut inner: D = D::new();
+        inner.update(&block.iter().map(|x| x ^ 0x36).collect::<Vec<u8>>());
+        let mut outer: D = D::new();
+        outer.update(&block.iter().map(|x| x ^ 0x5C).collect::<Vec<u8>>());
+        return Self {
+            inner: inner,
+            outer: outer,
+        };
+    }
+
+    /// 一次性计算.
+    pub fn mac(key: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut mac: Self = Self::new(key);
+        mac.update(data);
+        return mac.finalize();
+    }
+
+    /// 追加数据, 可以调用多次.
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+        return;
+    }
+
+    /// 结束并输出结果, 长度是D::OUTPUT_LENGTH.
+    pub fn finalize(self) -> Vec<u8> {
+        let mut outer: D = self.outer;
+        outer.update(&self.inner.finalize());
+        return outer.finalize();
+    }
+
+    /// 结束并输出小写的十六进制.
+    pub fn finalize_hex(self) -> String {
+        return HexEncoder::encode(&self.finalize()).to_ascii_lowercase();
+    }
+
+    /// 结束并输出Base64.
+    pub fn finalize_base64(self) -> String {
+        return Base64Encoder::encode(&self.finalize());
+    }
+
+    /// 结束并与expected比较, 比较的时间与内容无关.
+    pub fn verify(self, expected: &[u8]) -> bool {
+        return constant_time_eq(&self.finalize(), expected);
+    }
+}
+
+/* Function. */
+
+/// 比较两段字节, 长度相同时, 耗时与内容无关.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut difference: u8 = 0;
+    for (x, y) in a.iter().zip(b.iter()) {
+        difference |= x ^ y;
+    }
+    return difference == 0;
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table: [u32; 256] = [0; 256];
+    let mut x: usize = 0;
+    while x < 256 {
+        let mut crc: u32 = x as u32;
+        let mut y: usize = 0;
+        while y < 8 {
+            crc = if crc & 1 == 1 {
+                0xEDB88320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            y += 1;
+        }
+        table[x] = crc;
+        x += 1;
+    }
+    table
+};
+
+const MD5_TABLE: [u32; 64] = [
+    0xD76AA478, 0xE8C7B756, 0x242070DB, 0xC1BDCEEE, 0xF57C0FAF, 0x4787C62A, 0xA8304613, 0xFD469501,
+    0x698098D8, 0x8B44F7AF, 0xFFFF5BB1, 0x895CD7BE, 0x6B901122, 0xFD987193, 0xA679438E, 0x49B40821,
+    0xF61E2562, 0xC040B340, 0x265E5A51, 0xE9B6C7AA, 0xD62F105D, 0x02441453, 0xD8A1E681, 0xE7D3FBC8,
+    0x21E1CDE6, 0xC33707D6, 0xF4D50D87, 0x455A14ED, 0xA9E3E905, 0xFCEFA3F8, 0x676F02D9, 0x8D2A4C8A,
+    0xFFFA3942, 0x8771F681, 0x6D9D6122, 0xFDE5380C, 0xA4BEEA44, 0x4BDECFA9, 0xF6BB4B60, 0xBEBFBC70,
+    0x289B7EC6, 0xEAA127FA, 0xD4EF3085, 0x04881D05, 0xD9D4D039, 0xE6DB99E5, 0x1FA27CF8, 0xC4AC5665,
+    0xF4292244, 0x432AFF97, 0xAB9423A7, 0xFC93A039, 0x655B59C3, 0x8F0CCC92, 0xFFEFF47D, 0x85845DD1,
+    0x6FA87E4F, 0xFE2CE6E0, 0xA3014314, 0x4E0811A1, 0xF7537E82, 0xBD3AF235, 0x2AD7D2BB, 0xEB86D391,
+];
+
+const SHA256_TABLE: [u32; 64] = [
+    0x428A2F98, 0x71374491, 0xB5C0FBCF, 0xE9B5DBA5, 0x3956C25B, 0x59F111F1, 0x923F82A4, 0xAB1C5ED5,
+    0xD807AA98, 0x12835B01, 0x243185BE, 0x550C7DC3, 0x72BE5D74, 0x80DEB1FE, 0x9BDC06A7, 0xC19BF174,
+    0xE49B69C1, 0xEFBE4786, 0x0FC19DC6, 0x240CA1CC, 0x2DE92C6F, 0x4A7484AA, 0x5CB0A9DC, 0x76F988DA,
+    0x983E5152, 0xA831C66D, 0xB00327C8, 0xBF597FC7, 0xC6E00BF3, 0xD5A79147, 0x06CA6351, 0x14292967,
+    0x27B70A85, 0x2E1B2138, 0x4D2C6DFC, 0x53380D13, 0x650A7354, 0x766A0ABB, 0x81C2C92E, 0x92722C85,
+    0xA2BFE8A1, 0xA81A664B, 0xC24B8B70, 0xC76C51A3, 0xD192E819, 0xD6990624, 0xF40E3585, 0x106AA070,
+    0x19A4C116, 0x1E376C08, 0x2748774C, 0x34B0BCB5, 0x391C0CB3, 0x4ED8AA4A, 0x5B9CCA4F, 0x682E6FF3,
+    0x748F82EE, 0x78A5636F, 0x84C87814, 0x8CC70208, 0x90BEFFFA, 0xA4506CEB, 0xBEF9A3F7, 0xC67178F2,
+];
+
+const SHA512_TABLE: [u64; 80] = [
+    0x428A2F98D728AE22,
+    0x7137449123EF65CD,
+    0xB5C0FBCFEC4D3B2F,
+    0xE9B5DBA58189DBBC,
+    0x3956C25BF348B538,
+    0x59F111F1B605D019,
+    0x923F82A4AF194F9B,
+    0xAB1C5ED5DA6D8118,
+    0xD807AA98A3030242,
+    0x12835B0145706FBE,
+    0x243185BE4EE4B28C,
+    0x550C7DC3D5FFB4E2,
+    0x72BE5D74F27B896F,
+    0x80DEB1FE3B1696B1,
+    0x9BDC06A725C71235,
+    0xC19BF174CF692694,
+    0xE49B69C19EF14AD2,
+    0xEFBE4786384F25E3,
+    0x0FC19DC68B8CD5B5,
+    0x240CA1CC77AC9C65,
+    0x2DE92C6F592B0275,
+    0x4A7484AA6EA6E483,
+    0x5CB0A9DCBD41FBD4,
+    0x76F988DA831153B5,
+    0x983E5152EE66DFAB,
+    0xA831C66D2DB43210,
+    0xB00327C898FB213F,
+    0xBF597FC7BEEF0EE4,
+    0xC6E00BF33DA88FC2,
+    0xD5A79147930AA725,
+    0x06CA6351E003826F,
+    0x142929670A0E6E70,
+    0x27B70A8546D22FFC,
+    0x2E1B21385C26C926,
+    0x4D2C6DFC5AC42AED,
+    0x53380D139D95B3DF,
+    0x650A73548BAF63DE,
+    0x766A0ABB3C77B2A8,
+    0x81C2C92E47EDAEE6,
+    0x92722C851482353B,
+    0xA2BFE8A14CF10364,
+    0xA81A664BBC423001,
+    0xC24B8B70D0F89791,
+    0xC76C51A30654BE30,
+    0xD192E819D6EF5218,
+    0xD69906245565A910,
+    0xF40E35855771202A,
+    0x106AA07032BBD1B8,
+    0x19A4C116B8D2D0C8,
+    0x1E376C085141AB53,
+    0x2748774CDF8EEB99,
+    0x34B0BCB5E19B48A8,
+    0x391C0CB3C5C95A63,
+    0x4ED8AA4AE3418ACB,
+    0x5B9CCA4F7763E373,
+    0x682E6FF3D6B2B8A3,
+    0x748F82EE5DEFB2FC,
+    0x78A5636F43172F60,
+    0x84C87814A1F0AB72,
+    0x8CC702081A6439EC,
+    0x90BEFFFA23631E28,
+    0xA4506CEBDE82BDE9,
+    0xBEF9A3F7B2C67915,
+    0xC67178F2E372532B,
+    0xCA273ECEEA26619C,
+    0xD186B8C721C0C207,
+    0xEADA7DD6CDE0EB1E,
+    0xF57D4F7FEE6ED178,
+    0x06F067AA72176FBA,
+    0x0A637DC5A2C898A6,
+    0x113F9804BEF90DAE,
+    0x1B710B35131C471B,
+    0x28DB77F523047D84,
+    0x32CAAB7B40C72493,
+    0x3C9EBE0A15C9BEBC,
+    0x431D67C49C100D4C,
+    0x4CC5D4BECB3E42B6,
+    0x597F299CFC657E2A,
+    0x5FCB6FAB3AD6FAEC,
+    0x6C44198C4A475817,
+];
@@ -0,0 +1,55 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_hash::Adler32;
+use iceyee_hash::Crc32;
+use iceyee_hash::Digest;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_crc32() {
+    println!("");
+    println!("标准测试向量.");
+    assert_eq!(Crc32::digest(b""), vec![0, 0, 0, 0]);
+    let mut crc: Crc32 = Crc32::new();
+    crc.update(b"123456789");
+    assert_eq!(crc.value(), 0xCBF43926);
+    assert_eq!(crc.finalize_hex(), "cbf43926");
+    println!("分多次update.");
+    let mut crc: Crc32 = Crc32::new();
+    for _ in 0..1000 {
+        crc.update(&[b'a'; 1000]);
+    }
+    assert_eq!(crc.value(), 0xDC25BFBC);
+    return;
+}
+
+#[test]
+pub fn test_adler32() {
+    println!("");
+    println!("标准测试向量.");
+    assert_eq!(Adler32::digest(b""), vec![0, 0, 0, 1]);
+    let mut adler: Adler32 = Adler32::new();
+    adler.update(b"Wikipedia");
+    assert_eq!(adler.value(), 0x11E60398);
+    assert_eq!(adler.finalize_hex(), "11e60398");
+    println!("分多次update, 超过5552字节.");
+    let mut adler: Adler32 = Adler32::new();
+    for _ in 0..1000 {
+        adler.update(&[b'a'; 1000]);
+    }
+    assert_eq!(adler.value(), 0x15D870F9);
+    return;
+}
@@ -0,0 +1,180 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_hash::Digest;
+use iceyee_hash::Md5;
+use iceyee_hash::Sha1;
+use iceyee_hash::Sha256;
+use iceyee_hash::Sha384;
+use iceyee_hash::Sha512;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+const INPUT: [&str; 3] = [
+    "",
+    "abc",
+    "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+];
+
+/// 一百万个'a', 每次update的长度不一样.
+fn million<D: Digest>() -> String {
+    let data: Vec<u8> = vec![b'a'; 1000000];
+    let mut digest: D = D::new();
+    let mut offset: usize = 0;
+    let mut step: usize = 1;
+    while offset < data.len() {
+        let end: usize = (offset + step).min(data.len());
+        digest.update(&data[offset..end]);
+        offset = end;
+        step = step * 7 % 257 + 1;
+    }
+    return digest.finalize_hex();
+}
+
+/// 长度55到65, 覆盖补位时多压缩一组的边界.
+fn boundary<D: Digest>() {
+    for length in 55..=65 {
+        let data: Vec<u8> = vec![0x5A; length];
+        let mut digest: D = D::new();
+        digest.update(&data[..length / 2]);
+        digest.update(&data[length / 2..]);
+        assert_eq!(digest.finalize(), D::digest(&data));
+    }
+    return;
+}
+
+#[test]
+pub fn test_md5() {
+    println!("");
+    println!("RFC 1321.");
+    let expected: [&str; 3] = [
+        "d41d8cd98f00b204e9800998ecf8427e",
+        "900150983cd24fb0d6963f7d28e17f72",
+        "8215ef0796a20bcaaae116d3876c664a",
+    ];
+    for (input, expected) in INPUT.iter().zip(expected) {
+        let mut digest: Md5 = Md5::new();
+        digest.update(input.as_bytes());
+        assert_eq!(digest.finalize_hex(), expected);
+    }
+    assert_eq!(Md5::digest(b"abc").len(), Md5::OUTPUT_LENGTH);
+    assert_eq!(million::<Md5>(), "7707d6ae4e027c70eea2a935c2296f21");
+    boundary::<Md5>();
+    return;
+}
+
+#[test]
+pub fn test_sha1() {
+    println!("");
+    println!("RFC 3174.");
+    let expected: [&str; 3] = [
+        "da39a3ee5e6b4b0d3255bfef95601890afd80709",
+        "a9993e364706816aba3e25717850c26c9cd0d89d",
+        "84983e441c3bd26ebaae4aa1f95129e5e54670f1",
+    ];
+    for (input, expected) in INPUT.iter().zip(expected) {
+        let mut digest: Sha1 = Sha1::new();
+        digest.update(input.as_bytes());
+        assert_eq!(digest.finalize_hex(), expected);
+    }
+    assert_eq!(Sha1::digest(b"abc").len(), Sha1::OUTPUT_LENGTH);
+    assert_eq!(
+        million::<Sha1>(),
+        "34aa973cd4c4daa4f61eeb2bdbad27316534016f"
+    );
+    boundary::<Sha1>();
+    return;
+}
+
+#[test]
+pub fn test_sha256() {
+    println!("");
+    println!("FIPS 180-4.");
+    let expected: [&str; 3] = [
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+    ];
+    for (input, expected) in INPUT.iter().zip(expected) {
+        let mut digest: Sha256 = Sha256::new();
+        digest.update(input.as_bytes());
+        assert_eq!(digest.finalize_hex(), expected);
+    }
+    assert_eq!(Sha256::digest(b"abc").len(), Sha256::OUTPUT_LENGTH);
+    assert_eq!(
+        million::<Sha256>(),
+        "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+    );
+    boundary::<Sha256>();
+    println!("Base64输出.");
+    let mut digest: Sha256 = Sha256::new();
+    digest.update(b"abc");
+    assert_eq!(
+        digest.finalize_base64(),
+        "ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0="
+    );
+    return;
+}
+
+#[test]
+pub fn test_sha512() {
+    println!("");
+    println!("FIPS 180-4, SHA-384和SHA-512.");
+    let input: [&str; 3] = [
+        "",
+        "abc",
+        "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+    ];
+    let expected: [(&str, &str); 3] = [
+        (
+            "38b060a751ac96384cd9327eb1b1e36a21fdb71114be07434c0cc7bf63f6e1da274edebfe76f65fbd51ad2f14898b95b",
+            "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e",
+        ),
+        (
+            "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7",
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+        ),
+        (
+            "09330c33f71147e83d192fc782cd1b4753111b173b3b05d22fa08086e3b0f712fcc7c71a557e2db966c3e9fa91746039",
+            "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909",
+        ),
+    ];
+    for (input, expected) in input.iter().zip(expected) {
+        let mut digest: Sha384 = Sha384::new();
+        digest.update(input.as_bytes());
+        assert_eq!(digest.finalize_hex(), expected.0);
+        let mut digest: Sha512 = Sha512::new();
+        digest.update(input.as_bytes());
+        assert_eq!(digest.finalize_hex(), expected.1);
+    }
+    assert_eq!(Sha384::digest(b"abc").len(), Sha384::OUTPUT_LENGTH);
+    assert_eq!(Sha512::digest(b"abc").len(), Sha512::OUTPUT_LENGTH);
+    assert_eq!(
+        million::<Sha384>(),
+        "9d0e1809716474cb086e834e310a4a1ced149e9c00f248527972cec5704c2a5b07b8b3dc38ecc4ebae97ddd87f3d8985"
+    );
+    assert_eq!(
+        million::<Sha512>(),
+        "e718483d0ce769644e2e42c7bc15b4638e1f98b13b2044285632a803afa973ebde0ff244877ea60a4cb0432ce577c31beb009c5c2c49aa2e4eadb217ad8cc09b"
+    );
+    println!("长度111到129, 覆盖补位时多压缩一组的边界.");
+    for length in 111..=129 {
+        let data: Vec<u8> = vec![0x5A; length];
+        let mut digest: Sha512 = Sha512::new();
+        digest.update(&data[..length / 3]);
+        digest.update(&data[length / 3..]);
+        assert_eq!(digest.finalize(), Sha512::digest(&data));
+    }
+    return;
+}
@@ -0,0 +1,89 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_encoder::HexEncoder;
+use iceyee_hash::Hmac;
+use iceyee_hash::Md5;
+use iceyee_hash::Sha1;
+use iceyee_hash::Sha256;
+use iceyee_hash::Sha384;
+use iceyee_hash::Sha512;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_hmac() {
+    println!("");
+    println!("RFC 2202, RFC 4231, 测试1.");
+    let key: [u8; 20] = [0x0B; 20];
+    let mut mac: Hmac<Md5> = Hmac::new(&key);
+    mac.update(b"Hi There");
+    assert_eq!(mac.finalize_hex(), "5ccec34ea9656392457fa1ac27f08fbc");
+    let mut mac: Hmac<Sha1> = Hmac::new(&key);
+    mac.update(b"Hi ");
+    mac.update(b"There");
+    assert_eq!(
+        mac.finalize_hex(),
+        "b617318655057264e28bc0b6fb378c8ef146be00"
+    );
+    let mut mac: Hmac<Sha256> = Hmac::new(&key);
+    mac.update(b"Hi There");
+    assert_eq!(
+        mac.finalize_hex(),
+        "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7"
+    );
+    let mut mac: Hmac<Sha384> = Hmac::new(&key);
+    mac.update(b"Hi There");
+    assert_eq!(
+        mac.finalize_hex(),
+        "afd03944d84895626b0825f4ab46907f15f9dadbe4101ec682aa034c7cebc59cfaea9ea9076ede7f4af152e8b2fa9cb6"
+    );
+    let mut mac: Hmac<Sha512> = Hmac::new(&key);
+    mac.update(b"Hi There");
+    assert_eq!(
+        mac.finalize_hex(),
+        "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cdedaa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854"
+    );
+    println!("RFC 4231, 测试2, 短密钥.");
+    let expected: Vec<u8> =
+        HexEncoder::decode("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+            .expect("NEVER");
+    assert_eq!(
+        Hmac::<Sha256>::mac(b"Jefe", b"what do ya want for nothing?"),
+        expected
+    );
+    println!("RFC 4231, 测试6, 密钥超过分组长度.");
+    let key: [u8; 131] = [0xAA; 131];
+    let mut mac: Hmac<Sha256> = Hmac::new(&key);
+    mac.update(b"Test Using Larger Than Block-Size Key - Hash Key First");
+    assert_eq!(
+        mac.finalize_hex(),
+        "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+    );
+    let mut mac: Hmac<Sha512> = Hmac::new(&key);
+    mac.update(b"Test Using Larger Than Block-Size Key - Hash Key First");
+    assert_eq!(
+        mac.finalize_hex(),
+        "80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f3526b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598"
+    );
+    println!("verify.");
+    let mut mac: Hmac<Sha256> = Hmac::new(b"Jefe");
+    mac.update(b"what do ya want for nothing?");
+    assert!(mac.clone().verify(&expected));
+    assert!(!mac.clone().verify(&expected[..31]));
+    let mut wrong: Vec<u8> = expected.clone();
+    wrong[31] ^= 1;
+    assert!(!mac.verify(&wrong));
+    return;
+}
@@ -0,0 +1,286 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+
+//! Json Web Token, RFC 7519.
+//!
+//! 支持HS256, HS384, HS512, 各个部分用Base64编码, 即[Base64Config::URL_SAFE_NO_PAD].
+//!
+//! # Example
+//! ```
+//! use iceyee_jwt::Jwt;
+//! use iceyee_jwt::JwtAlgorithm;
+//!
+//! let jwt: Jwt = Jwt::new(JwtAlgorithm::HS256, b"secret").set_leeway(30);
+//! let token: String = jwt
+//!     .encode(&serde_json::json!({"sub": "iceyee", "admin": true}))
+//!     .expect("NEVER");
+//! let claims: serde_json::Value = jwt.decode(&token).expect("NEVER");
+//! assert_eq!(claims["sub"], "iceyee");
+//! ```
+
+/* Use. */
+
+use iceyee_encoder::Base64Config;
+use iceyee_encoder::Base64Encoder;
+use iceyee_hash::Hmac;
+use iceyee_hash::Sha256;
+use iceyee_hash::Sha384;
+use iceyee_hash::Sha512;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Map;
+use serde_json::Value;
+
+/* Enum. */
+
+/// 签发或者校验令牌的异常.
+///
+/// - BadFormat, 错误的格式, 不是三段, Base64或者json无效, 或者时间声明不是数字.
+/// - Algorithm, 算法不匹配, 头部的'alg'与[Jwt::algorithm]不同, 包括'none'.
+/// - Signature, 签名不匹配.
+/// - Expired, 令牌已过期.
+/// - NotYetValid, 令牌尚未生效.
+/// - IssuedInFuture, 签发时间晚于当前时间.
+/// - Claims, 声明序列化或者反序列化失败, 附带serde的异常信息.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JwtError {
+    BadFormat,
+    Algorithm,
+    Signature,
+    Expired,
+    NotYetValid,
+    IssuedInFuture,
+    Claims(String),
+}
+
+impl std::fmt::Display for JwtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        return match self {
+            Self::BadFormat => f.write_str("错误的格式"),
+            Self::Algorithm => f.write_str("算法不匹配"),
+            Self::Signature => f.write_str("签名不匹配"),
+            Self::Expired => f.write_str("令牌已过期"),
+            Self::NotYetValid => f.write_str("令牌尚未生效"),
+            Self::IssuedInFuture => f.write_str("签发时间晚于当前时间"),
+            Self::Claims(message) => write!(f, "声明无效, {}", message),
+        };
+    }
+}
+
+impl std::error::Error for JwtError {}
+
+/// 签名算法.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JwtAlgorithm {
+    HS256,
+    HS384,
+    HS512,
+}
+
+impl JwtAlgorithm {
+    /// 头部'alg'的值.
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Self::HS256 => "HS256",
+            Self::HS384 => "HS384",
+            Self::HS512 => "HS512",
+        };
+    }
+
+    /// 从头部'alg'的值解析, 不支持的算法返回[None].
+    pub fn from_name(name: &str) -> Option<Self> {
+        return match name {
+            "HS256" => Some(Self::HS256),
+            "HS384" => Some(Self::HS384),
+            "HS512" => Some(Self::HS512),
+            _ => None,
+        };
+    }
+
+    fn sign(&self, key: &[u8], data: &[u8]) -> Vec<u8> {
+        return match self {
+            Self::HS256 => Hmac::<Sha256>::mac(key, data),
+            Self::HS384 => Hmac::<Sha384>::mac(key, data),
+            Self::HS512 => Hmac::<Sha512>::mac(key, data),
+        };
+    }
+}
+
+/* Trait. */
+
+/* Struct. */
+
+/// 令牌解析出的头部和声明, 都是json对象, 见[Jwt::decode_unverified].
+pub type JwtParts = (Map<String, Value>, Map<String, Value>);
+
+/// 令牌的签发和校验.
+///
+/// 校验时, 除了签名, 还检查声明里的'exp', 'nbf', 'iat', 与[iceyee_time::now]比较, 允许leeway秒的误差.
+///
+/// - exp, 过期时间, 当前时间不早于exp+leeway则失败, 即到达exp时已经过期.
+/// - nbf, 生效时间, 当前时间早于nbf-leeway则失败.
+/// - iat, 签发时间, 当前时间早于iat-leeway则失败.
+#[derive(Clone)]
+pub struct Jwt {
+    algorithm: JwtAlgorithm,
+    key: Vec<u8>,
+    leeway: i64,
+}
+
+impl Jwt {
+    /// 密钥可以是任意长度.
+    pub fn new(algorithm: JwtAlgorithm, key: &[u8]) -> Self {
+        return Self {
+            algorithm: algorithm,
+            key: key.to_vec(),
+            leeway: 0,
+        };
+    }
+
+    /// 允许的时间误差, 单位:秒, 默认0.
+    pub fn set_leeway(mut self, leeway: u64) -> Self {
+        self.leeway = leeway.min(i64::MAX as u64) as i64;
+        return self;
+    }
+
+    pub fn algorithm(&self) -> JwtAlgorithm {
+        return self.algorithm;
+    }
+
+    pub fn leeway(&self) -> u64 {
+        return self.leeway as u64;
+    }
+
+    /// 签发令牌, claims可以是[Value]或者任意实现了[Serialize]的类型.
+    ///
+    /// - @exception [JwtError::Claims] 声明序列化失败.
+    pub fn encode<T>(&self, claims: &T) -> Result<String, JwtError>
+    where
+        T: Serialize,
+    {
+        let header: Value = serde_json::json!({"alg": self.algorithm.name(), "typ": "JWT"});
+        let header: Vec<u8> =
+            serde_json::to_vec(&header).map_err(|e| JwtError::Claims(e.to_string()))?;
+        let claims: Vec<u8> =
+            serde_json::to_vec(claims).map_err(|e| JwtError::Claims(e.to_string()))?;
+        let mut token: String = String::new();
+        token.push_str(&Base64Encoder::encode_with(
+            &header,
+            &Base64Config::URL_SAFE_NO_PAD,
+        ));
+        token.push('.');
+        token.push_str(&Base64Encoder::encode_with(
+            &claims,
+            &Base64Config::URL_SAFE_NO_PAD,
+        ));
+        let signature: Vec<u8> = self.algorithm.sign(&self.key, token.as_bytes());
+        token.push('.');
+        token.push_str(&Base64Encoder::encode_with(
+            &signature,
+            &Base64Config::URL_SAFE_NO_PAD,
+        ));
+        return Ok(token);
+    }
+
+    /// 校验令牌并返回声明, T可以是[Value]或者任意实现了[DeserializeOwned]的类型.
+    ///
+    /// - @exception [JwtError::BadFormat] 不是三段, Base64或者json无效.
+    /// - @exception [JwtError::Algorithm] 头部的'alg'与[Jwt::algorithm]不同, 包括'none'.
+    /// - @exception [JwtError::Signature] 签名不匹配.
+    /// - @exception [JwtError::Expired] 令牌已过期.
+    /// - @exception [JwtError::NotYetValid] 令牌尚未生效.
+    /// - @exception [JwtError::IssuedInFuture] 签发时间晚于当前时间.
+    /// - @exception [JwtError::Claims] 声明反序列化失败.
+    pub fn decode<T>(&self, token: &str) -> Result<T, JwtError>
+    where
+        T: DeserializeOwned,
+    {
+        let (header, claims) = Self::decode_unverified(token)?;
+        let algorithm: Option<JwtAlgorithm> = header
+            .get("alg")
+            .and_then(|x| x.as_str())
+            .and_then(JwtAlgorithm::from_name);
+        if algorithm != Some(self.algorithm) {
+            return Err(JwtError::Algorithm);
+        }
+        let index: usize = token.rfind('.').expect("NEVER");
+        let signature: Vec<u8> =
+            Base64Encoder::decode_with(&token[index + 1..], &Base64Config::URL_SAFE_NO_PAD)
+                .map_err(|_| JwtError::BadFormat)?;
+        let expected: Vec<u8> = self.algorithm.sign(&self.key, &token.as_bytes()[..index]);
+        if !iceyee_hash::constant_time_eq(&signature, &expected) {
+            return Err(JwtError::Signature);
+        }
+        let now: i64 = iceyee_time::now_seconds();
+        if let Some(exp) = Self::time(&claims, "exp")?
+            && exp.saturating_add(self.leeway) <= now
+        {
+            return Err(JwtError::Expired);
+        }
+        if let Some(nbf) = Self::time(&claims, "nbf")?
+            && now.saturating_add(self.leeway) < nbf
+        {
+            return Err(JwtError::NotYetValid);
+        }
+        if let Some(iat) = Self::time(&claims, "iat")?
+            && now.saturating_add(self.leeway) < iat
+        {
+            return Err(JwtError::IssuedInFuture);
+        }
+        return serde_json::from_value(Value::Object(claims))
+            .map_err(|e| JwtError::Claims(e.to_string()));
+    }
+
+    /// 不校验签名和时间, 只解析出头部和声明.
+    ///
+    /// - @exception [JwtError::BadFormat] 不是三段, Base64或者json无效, 或者不是json对象.
+    pub fn decode_unverified(token: &str) -> Result<JwtParts, JwtError> {
+        let parts: Vec<&str> = token.split('.').collect();
+        if parts.len() != 3 {
+            return Err(JwtError::BadFormat);
+        }
+        let header: Map<String, Value> = Self::part(parts[0])?;
+        let claims: Map<String, Value> = Self::part(parts[1])?;
+        return Ok((header, claims));
+    }
+
+    fn part(part: &str) -> Result<Map<String, Value>, JwtError> {
+        let part: Vec<u8> = Base64Encoder::decode_with(part, &Base64Config::URL_SAFE_NO_PAD)
+            .map_err(|_| JwtError::BadFormat)?;
+        return match serde_json::from_slice(&part) {
+            Ok(Value::Object(x)) => Ok(x),
+            _ => Err(JwtError::BadFormat),
+        };
+    }
+
+    /// 时间声明是秒数, 允许小数.
+    fn time(claims: &Map<String, Value>, name: &str) -> Result<Option<i64>, JwtError> {
+        return match claims.get(name) {
+            None => Ok(None),
+            Some(Value::Number(x)) => match x.as_i64() {
+                Some(x) => Ok(Some(x)),
+                None => match x.as_f64() {
+                    Some(x) => Ok(Some(x.floor() as i64)),
+                    None => Err(JwtError::BadFormat),
+                },
+            },
+            Some(_) => Err(JwtError::BadFormat),
+        };
+    }
+}
+
+impl std::fmt::Debug for Jwt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f
+            .debug_struct("Jwt")
+            .field("algorithm", &self.algorithm)
+            .field("leeway", &self.leeway)
+            .finish_non_exhaustive();
+    }
+}
+
+/* Function. */
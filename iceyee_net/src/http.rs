@@ -10,7 +10,12 @@
 pub mod client;
 pub mod server;
 
+use iceyee_encoder::Gb18030Encoder;
+use iceyee_encoder::PunycodeEncoder;
+use iceyee_encoder::UrlEncodeSet;
 use iceyee_encoder::UrlEncoder;
+use iceyee_error::ErrorKind;
+use iceyee_error::IceyeeError;
 use std::collections::BTreeMap;
 use std::time::Duration;
 use tokio::io::AsyncRead;
@@ -163,6 +168,9 @@ pub struct Args {
 
 impl ToString for Args {
     /// 转字符串, 如'?a=1&b=2&b=3', 包含url编码.
+    ///
+    /// 键和值按[Query](UrlEncodeSet::Query)编码, 空格编码成'%20', '+'编码成'%2B',
+    /// 所以不管服务器是否把'+'当作空格, 都能得到原来的内容.
     fn to_string(&self) -> String {
         let mut output: String = String::new();
         let mut keys = Vec::from_iter(self.hm.keys());
@@ -174,9 +182,9 @@ impl ToString for Args {
                 } else {
                     output.push_str("&");
                 }
-                output.push_str(UrlEncoder::encode(key).as_str());
+                output.push_str(UrlEncoder::encode_component(key, UrlEncodeSet::Query).as_str());
                 output.push_str("=");
-                output.push_str(UrlEncoder::encode(value).as_str());
+                output.push_str(UrlEncoder::encode_component(value, UrlEncodeSet::Query).as_str());
             }
         }
         return output;
@@ -243,6 +251,8 @@ enum State {
 /// 统一资源定位器, Uniform Resource Locator.
 ///
 /// http_URL = "http:" "//" host \[ ":" port \] \[ abs_path \]
+///
+/// 解析时host经过[PunycodeEncoder::to_ascii], 转成小写, 国际化域名转成'xn--'开头.
 #[derive(Clone, Debug)]
 pub struct Url {
     pub protocol: String,
@@ -283,9 +293,9 @@ impl std::str::FromStr for Url {
                 State::Protocol => {
                     if value[index] == b'/' {
                         let protocol: String = String::from_utf8(buffer.to_vec())
-                            .map_err(|e| iceyee_error::c!(e, link))?;
+                            .map_err(|e| iceyee_error::a!(e, link))?;
                         if !protocol.ends_with(":") {
-                            return Err(iceyee_error::c!(link));
+                            return Err(iceyee_error::a!(link));
                         }
                         url.protocol = protocol;
                         buffer.clear();
@@ -299,11 +309,12 @@ impl std::str::FromStr for Url {
                 State::Host => match value[index] {
                     b':' | b'/' | b'?' | b'#' => {
                         let host: String = String::from_utf8(buffer.to_vec())
-                            .map_err(|e| iceyee_error::c!(e, link))?;
+                            .map_err(|e| iceyee_error::a!(e, link))?;
                         if host.len() == 0 {
-                            return Err(iceyee_error::c!(link));
+                            return Err(iceyee_error::a!(link));
                         }
-                        url.host = host;
+                        url.host = PunycodeEncoder::to_ascii(&host)
+                            .map_err(|e| iceyee_error::a!(e, link))?;
                         buffer.clear();
                         match value[index] {
                             b':' => {
@@ -335,11 +346,11 @@ impl std::str::FromStr for Url {
                 State::Port => match value[index] {
                     b'/' | b'?' | b'#' => {
                         let port: u16 = String::from_utf8(buffer.to_vec())
-                            .map_err(|e| iceyee_error::c!(e, link))?
+                            .map_err(|e| iceyee_error::a!(e, link))?
                             .parse::<u16>()
-                            .map_err(|e| iceyee_error::c!(e, link))?;
+                            .map_err(|e| iceyee_error::a!(e, link))?;
                         if port == 0 {
-                            return Err(iceyee_error::c!(link));
+                            return Err(iceyee_error::a!(link));
                         }
                         url.port = port;
                         buffer.clear();
@@ -369,7 +380,7 @@ impl std::str::FromStr for Url {
                 State::Path => match value[index] {
                     b'?' | b'#' => {
                         let path: String = String::from_utf8(buffer.to_vec())
-                            .map_err(|e| iceyee_error::c!(e, link))?;
+                            .map_err(|e| iceyee_error::a!(e, link))?;
                         url.path = path;
                         buffer.clear();
                         match value[index] {
@@ -394,7 +405,7 @@ impl std::str::FromStr for Url {
                 State::Query => match value[index] {
                     b'#' => {
                         let query: String = String::from_utf8(buffer.to_vec())
-                            .map_err(|e| iceyee_error::c!(e, link))?;
+                            .map_err(|e| iceyee_error::a!(e, link))?;
                         url.query = Some(query);
                         buffer.clear();
                         state = State::Fragment;
@@ -413,39 +424,40 @@ impl std::str::FromStr for Url {
         } // while index < length
         match state {
             State::Protocol => {
-                return Err(iceyee_error::c!(link));
+                return Err(iceyee_error::a!(link));
             }
             State::Host => {
                 let host: String =
-                    String::from_utf8(buffer.to_vec()).map_err(|e| iceyee_error::c!(e, link))?;
+                    String::from_utf8(buffer.to_vec()).map_err(|e| iceyee_error::a!(e, link))?;
                 if host.len() == 0 {
-                    return Err(iceyee_error::c!(link));
+                    return Err(iceyee_error::a!(link));
                 }
-                url.host = host;
+                url.host =
+                    PunycodeEncoder::to_ascii(&host).map_err(|e| iceyee_error::a!(e, link))?;
             }
             State::Port => {
                 let port: u16 = String::from_utf8(buffer.to_vec())
-                    .map_err(|e| iceyee_error::c!(e, link))?
+                    .map_err(|e| iceyee_error::a!(e, link))?
                     .parse::<u16>()
-                    .map_err(|e| iceyee_error::c!(e, link))?;
+                    .map_err(|e| iceyee_error::a!(e, link))?;
                 if port == 0 {
-                    return Err(iceyee_error::c!(link));
+                    return Err(iceyee_error::a!(link));
                 }
                 url.port = port;
             }
             State::Path => {
                 let path: String =
-                    String::from_utf8(buffer.to_vec()).map_err(|e| iceyee_error::c!(e, link))?;
+                    String::from_utf8(buffer.to_vec()).map_err(|e| iceyee_error::a!(e, link))?;
                 url.path = path;
             }
             State::Query => {
                 let query: String =
-                    String::from_utf8(buffer.to_vec()).map_err(|e| iceyee_error::c!(e, link))?;
+                    String::from_utf8(buffer.to_vec()).map_err(|e| iceyee_error::a!(e, link))?;
                 url.query = Some(query);
             }
             State::Fragment => {
                 let fragment: String =
-                    String::from_utf8(buffer.to_vec()).map_err(|e| iceyee_error::c!(e, link))?;
+                    String::from_utf8(buffer.to_vec()).map_err(|e| iceyee_error::a!(e, link))?;
                 url.fragment = Some(fragment);
             }
         }
@@ -579,7 +591,7 @@ impl Request {
     /// 解析数据.
     ///
     /// - @param timeout 超时, 可选, 默认1分钟.
-    pub async fn read_from<R>(mut input: R, timeout: Option<u64>) -> Result<Request, String>
+    pub async fn read_from<R>(mut input: R, timeout: Option<u64>) -> Result<Request, IceyeeError>
     where
         R: AsyncRead + Unpin,
     {
@@ -611,11 +623,11 @@ impl Request {
                 )
                 .await
                 {
-                    Ok(length) => length.map_err(|e| iceyee_error::c!(e))?,
-                    Err(_) => return Err(iceyee_error::c!("TimedOut")),
+                    Ok(length) => length.map_err(|e| IceyeeError::from_std(e.kind().into(), e))?,
+                    Err(_) => iceyee_error::bail!(Timeout; "TimedOut"),
                 };
                 if length == 0 {
-                    return Err(iceyee_error::c!("UnexpectedEof"));
+                    iceyee_error::bail!(Eof; "UnexpectedEof");
                 }
                 buffer.extend(&buf, length);
             }
@@ -650,7 +662,7 @@ impl Request {
                     while x < buffer.length {
                         if buffer.block[x].is_ascii_whitespace() {
                             request.method = String::from_utf8(bytes.clone())
-                                .map_err(|e| iceyee_error::c!(e))?;
+                                .map_err(|e| IceyeeError::from_std(ErrorKind::BadFormat, e))?;
                             bytes.clear();
                             state = State::PathSpace;
                             break;
@@ -658,7 +670,7 @@ impl Request {
                             bytes.push(buffer.block[x]);
                             x += 1;
                             if 0xFF < bytes.len() {
-                                return Err(iceyee_error::c!("大小非预期"));
+                                iceyee_error::bail!(BadFormat; "大小非预期");
                             }
                         }
                     }
@@ -667,7 +679,7 @@ impl Request {
                     while x < buffer.length {
                         if buffer.block[x].is_ascii_whitespace() {
                             request.path = String::from_utf8(bytes.clone())
-                                .map_err(|e| iceyee_error::c!(e))?;
+                                .map_err(|e| IceyeeError::from_std(ErrorKind::BadFormat, e))?;
                             bytes.clear();
                             state = State::VersionSpace;
                             if request.path.contains("?") {
@@ -682,7 +694,7 @@ impl Request {
                             bytes.push(buffer.block[x]);
                             x += 1;
                             if 0xFFF < bytes.len() {
-                                return Err(iceyee_error::c!("大小非预期"));
+                                iceyee_error::bail!(BadFormat; "大小非预期");
                             }
                         }
                     }
@@ -691,7 +703,7 @@ impl Request {
                     while x < buffer.length {
                         if buffer.block[x].is_ascii_whitespace() {
                             request.version = String::from_utf8(bytes.clone())
-                                .map_err(|e| iceyee_error::c!(e))?;
+                                .map_err(|e| IceyeeError::from_std(ErrorKind::BadFormat, e))?;
                             bytes.clear();
                             state = State::Header;
                             needed = 4;
@@ -700,7 +712,7 @@ impl Request {
                             bytes.push(buffer.block[x]);
                             x += 1;
                             if 0xFF < bytes.len() {
-                                return Err(iceyee_error::c!("大小非预期"));
+                                iceyee_error::bail!(BadFormat; "大小非预期");
                             }
                         }
                     }
@@ -713,7 +725,7 @@ impl Request {
                             && buffer.block[x + 3] == b'\n'
                         {
                             let header: String = String::from_utf8(bytes.clone())
-                                .map_err(|e| iceyee_error::c!(e))?;
+                                .map_err(|e| IceyeeError::from_std(ErrorKind::BadFormat, e))?;
                             bytes.clear();
                             state = State::BodySpace;
                             for line in header.split("\r\n") {
@@ -729,7 +741,7 @@ impl Request {
                             bytes.push(buffer.block[x]);
                             x += 1;
                             if 0xFFFF < bytes.len() {
-                                return Err(iceyee_error::c!("大小非预期"));
+                                iceyee_error::bail!(BadFormat; "大小非预期");
                             }
                         }
                     }
@@ -747,10 +759,10 @@ impl Request {
                             .unwrap()
                             .trim()
                             .parse::<usize>()
-                            .map_err(|e| iceyee_error::c!(e))?
+                            .map_err(|e| IceyeeError::from_std(ErrorKind::BadFormat, e))?
                     };
                     if 0x3FFFFFFF < needed {
-                        return Err(iceyee_error::c!("大小非预期"));
+                        iceyee_error::bail!(BadFormat; "大小非预期");
                     }
                 }
                 State::Body => {
@@ -851,10 +863,59 @@ impl Response {
         return output;
     }
 
+    /// 头部Content-Type里的charset, 统一转成小写, 没有则返回[None].
+    pub fn charset(&self) -> Option<String> {
+        for (key, values) in self.header.iter() {
+            if !key.eq_ignore_ascii_case("Content-Type") {
+                continue;
+            }
+            for value in values {
+                for parameter in value.split(';').skip(1) {
+                    let Some((name, charset)) = parameter.split_once('=') else {
+                        continue;
+                    };
+                    if name.trim().eq_ignore_ascii_case("charset") {
+                        let charset: &str = charset.trim().trim_matches('"').trim();
+                        if !charset.is_empty() {
+                            return Some(charset.to_ascii_lowercase());
+                        }
+                    }
+                }
+            }
+        }
+        return None;
+    }
+
+    /// 按照[Response::charset]把正文解码成文本, 没有charset则视为utf-8.
+    ///
+    /// 支持utf-8, gbk, gb2312, gb18030, iso-8859-1, us-ascii.
+    ///
+    /// - @exception 正文不符合对应的编码.
+    /// - @exception 不支持的字符集.
+    pub fn text(&self) -> Result<String, String> {
+        let charset: String = self.charset().unwrap_or("utf-8".to_string());
+        return match charset.as_str() {
+            "utf-8" | "utf8" => {
+                String::from_utf8(self.body.clone()).map_err(|e| iceyee_error::c!(e, charset))
+            }
+            "gbk" | "gb2312" | "gb18030" | "x-gbk" => {
+                Gb18030Encoder::decode(&self.body).map_err(|e| iceyee_error::b!(e, charset))
+            }
+            "iso-8859-1" | "latin1" => Ok(self.body.iter().map(|x| *x as char).collect()),
+            "us-ascii" | "ascii" => {
+                if !self.body.is_ascii() {
+                    return Err(iceyee_error::c!("出现非ascii字符", charset));
+                }
+                Ok(self.body.iter().map(|x| *x as char).collect())
+            }
+            _ => Err(iceyee_error::c!("不支持的字符集", charset)),
+        };
+    }
+
     /// 解析数据.
     ///
     /// - @param timeout 超时, 可选, 默认1分钟.
-    pub async fn read_from<R>(mut input: R, timeout: Option<u64>) -> Result<Response, String>
+    pub async fn read_from<R>(mut input: R, timeout: Option<u64>) -> Result<Response, IceyeeError>
     where
         R: AsyncRead + Unpin,
     {
@@ -890,11 +951,11 @@ impl Response {
                 )
                 .await
                 {
-                    Ok(length) => length.map_err(|e| iceyee_error::c!(e))?,
-                    Err(_) => return Err(iceyee_error::c!("TimedOut")),
+                    Ok(length) => length.map_err(|e| IceyeeError::from_std(e.kind().into(), e))?,
+                    Err(_) => iceyee_error::bail!(Timeout; "TimedOut"),
                 };
                 if length == 0 {
-                    return Err(iceyee_error::c!("UnexpectedEof"));
+                    iceyee_error::bail!(Eof; "UnexpectedEof");
                 }
                 buffer.extend(&buf, length);
             }
@@ -914,7 +975,7 @@ impl Response {
                     while x < buffer.length {
                         if buffer.block[x].is_ascii_whitespace() {
                             response.version = String::from_utf8(bytes.clone())
-                                .map_err(|e| iceyee_error::c!(e))?;
+                                .map_err(|e| IceyeeError::from_std(ErrorKind::BadFormat, e))?;
                             bytes.clear();
                             state = State::StatusCodeSpace;
                             break;
@@ -938,9 +999,9 @@ impl Response {
                     while x < buffer.length {
                         if buffer.block[x].is_ascii_whitespace() {
                             response.status_code = String::from_utf8(bytes.clone())
-                                .map_err(|e| iceyee_error::c!(e))?
+                                .map_err(|e| IceyeeError::from_std(ErrorKind::BadFormat, e))?
                                 .parse::<u16>()
-                                .map_err(|e| iceyee_error::c!(e))?;
+                                .map_err(|e| IceyeeError::from_std(ErrorKind::BadFormat, e))?;
                             bytes.clear();
                             state = State::StatusSpace;
                             needed = 2;
@@ -970,7 +1031,7 @@ impl Response {
                     while x + 1 < buffer.length {
                         if buffer.block[x] == b'\r' && buffer.block[x + 1] == b'\n' {
                             response.status = String::from_utf8(bytes.clone())
-                                .map_err(|e| iceyee_error::c!(e))?;
+                                .map_err(|e| IceyeeError::from_std(ErrorKind::BadFormat, e))?;
                             bytes.clear();
                             state = State::Header;
                             needed = 4;
@@ -989,7 +1050,7 @@ impl Response {
                             && buffer.block[x + 3] == b'\n'
                         {
                             let a001: String = String::from_utf8(bytes.clone())
-                                .map_err(|e| iceyee_error::c!(e))?;
+                                .map_err(|e| IceyeeError::from_std(ErrorKind::BadFormat, e))?;
                             bytes.clear();
                             for line in a001.split("\r\n") {
                                 if line.contains(":") {
@@ -1015,7 +1076,7 @@ impl Response {
                     if response.header.contains_key("Content-Length") {
                         needed = response.header.get("Content-Length").unwrap().as_slice()[0]
                             .parse::<usize>()
-                            .map_err(|e| iceyee_error::c!(e))?;
+                            .map_err(|e| IceyeeError::from_std(ErrorKind::BadFormat, e))?;
                         state = State::Body;
                     } else if response.header.contains_key("Transfer-Encoding") {
                         needed = 2;
@@ -1046,9 +1107,9 @@ impl Response {
                     while x + 1 < buffer.length {
                         if buffer.block[x] == b'\r' && buffer.block[x + 1] == b'\n' {
                             let a001: String = String::from_utf8(bytes.clone())
-                                .map_err(|e| iceyee_error::c!(e))?;
+                                .map_err(|e| IceyeeError::from_std(ErrorKind::BadFormat, e))?;
                             needed = usize::from_str_radix(&a001, 16)
-                                .map_err(|e| iceyee_error::c!(e))?;
+                                .map_err(|e| IceyeeError::from_std(ErrorKind::BadFormat, e))?;
                             bytes.clear();
                             if needed == 0 {
                                 needed = 2;
@@ -1083,7 +1144,7 @@ impl Response {
                 }
                 State::ChunkSpace => {
                     if buffer.block[0] != b'\r' || buffer.block[1] != b'\n' {
-                        return Err(iceyee_error::c!("非预期的格式"));
+                        iceyee_error::bail!(BadFormat; "非预期的格式");
                     }
                     bytes.clear();
                     x += 2;
@@ -1092,7 +1153,7 @@ impl Response {
                 }
                 State::ChunkEnd => {
                     if buffer.block[0] != b'\r' || buffer.block[1] != b'\n' {
-                        return Err(iceyee_error::c!("非预期的格式"));
+                        iceyee_error::bail!(BadFormat; "非预期的格式");
                     }
                     buffer.roll(2);
                     break 'A;
@@ -15,6 +15,9 @@ use crate::http::Args;
 use crate::http::Url;
 use async_compression::tokio::bufread::GzipDecoder;
 use iceyee_encoder::Base64Encoder;
+use iceyee_error::ErrorChain;
+use iceyee_error::ErrorKind;
+use iceyee_error::IceyeeError;
 use iceyee_random::Random;
 use serde::Deserialize;
 use serde::Serialize;
@@ -46,7 +49,7 @@ pub trait Proxy: AsyncRead + AsyncWrite + Send + Sync + Unpin {
         target_host: &str,
         target_port: u16,
         using_ssl: bool,
-    ) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'b>>
+    ) -> Pin<Box<dyn Future<Output = Result<(), IceyeeError>> + Send + 'b>>
     where
         'a: 'b;
 
@@ -140,7 +143,7 @@ impl Proxy for NoProxy {
         target_host: &str,
         target_port: u16,
         using_ssl: bool,
-    ) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'b>>
+    ) -> Pin<Box<dyn Future<Output = Result<(), IceyeeError>> + Send + 'b>>
     where
         'a: 'b,
     {
@@ -155,21 +158,21 @@ impl Proxy for NoProxy {
             let plain_socket: TokioTcpStream =
                 TokioTcpStream::connect((target_host.clone(), target_port))
                     .await
-                    .map_err(|e| iceyee_error::c!(e))?;
+                    .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
             plain_socket
                 .set_nodelay(true)
-                .map_err(|e| iceyee_error::c!(e))?;
+                .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
             if !using_ssl {
                 self.plain_socket = Some(plain_socket);
             } else {
                 self.logger.push_str("建立tls\r\n");
                 let connector = tokio_native_tls::native_tls::TlsConnector::new()
-                    .map_err(|e| iceyee_error::c!(e))?;
+                    .map_err(|e| IceyeeError::from_std(ErrorKind::Other, e))?;
                 let connector = tokio_native_tls::TlsConnector::from(connector);
                 let ssl_socket: TlsStream<TokioTcpStream> = connector
                     .connect(&target_host, plain_socket)
                     .await
-                    .map_err(|e| iceyee_error::c!(e))?;
+                    .map_err(|e| IceyeeError::from_std(ErrorKind::Other, e))?;
                 self.ssl_socket = Some(ssl_socket);
             }
             let message: String = format!("连接耗时: {}ms\r\n", iceyee_time::now() - t);
@@ -235,7 +238,7 @@ impl Proxy for HttpProxy {
         target_host: &str,
         target_port: u16,
         using_ssl: bool,
-    ) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'b>>
+    ) -> Pin<Box<dyn Future<Output = Result<(), IceyeeError>> + Send + 'b>>
     where
         'a: 'b,
     {
@@ -251,10 +254,10 @@ impl Proxy for HttpProxy {
             let mut plain_socket: TokioTcpStream =
                 TokioTcpStream::connect((self.proxy_host.clone(), self.proxy_port))
                     .await
-                    .map_err(|e| iceyee_error::c!(e))?;
+                    .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
             plain_socket
                 .set_nodelay(true)
-                .map_err(|e| iceyee_error::c!(e))?;
+                .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
             /* 2 CONNECT. */
             let mut request: Request = Request::default();
             request.method = "CONNECT".to_string();
@@ -282,7 +285,7 @@ impl Proxy for HttpProxy {
             plain_socket
                 .write(request.to_string().as_bytes())
                 .await
-                .map_err(|e| iceyee_error::c!(e))?;
+                .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
             /* CONNECT响应. */
             let response: Response = Response::read_from(&mut plain_socket, None).await?;
             if 200 <= response.status_code && response.status_code < 300 {
@@ -298,7 +301,7 @@ impl Proxy for HttpProxy {
                     "请求代理连接失败 @proxy='{}:{}'",
                     self.proxy_host, self.proxy_port
                 );
-                return Err(iceyee_error::c!(message));
+                iceyee_error::bail!(message);
             }
             /* 3 tls握手. */
             if !using_ssl {
@@ -306,12 +309,12 @@ impl Proxy for HttpProxy {
             } else {
                 self.logger.push_str("建立tls\r\n");
                 let connector = tokio_native_tls::native_tls::TlsConnector::new()
-                    .map_err(|e| iceyee_error::c!(e))?;
+                    .map_err(|e| IceyeeError::from_std(ErrorKind::Other, e))?;
                 let connector = tokio_native_tls::TlsConnector::from(connector);
                 let ssl_socket: TlsStream<TokioTcpStream> = connector
                     .connect(&target_host, plain_socket)
                     .await
-                    .map_err(|e| iceyee_error::c!(e))?;
+                    .map_err(|e| IceyeeError::from_std(ErrorKind::Other, e))?;
                 self.ssl_socket = Some(ssl_socket);
             }
             let message: String = format!("连接耗时: {}ms\r\n", iceyee_time::now() - t);
@@ -377,7 +380,7 @@ impl Proxy for Socks5Proxy {
         target_host: &str,
         target_port: u16,
         using_ssl: bool,
-    ) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'b>>
+    ) -> Pin<Box<dyn Future<Output = Result<(), IceyeeError>> + Send + 'b>>
     where
         'a: 'b,
     {
@@ -393,10 +396,10 @@ impl Proxy for Socks5Proxy {
             let mut plain_socket: TokioTcpStream =
                 TokioTcpStream::connect((self.proxy_host.clone(), self.proxy_port))
                     .await
-                    .map_err(|e| iceyee_error::c!(e))?;
+                    .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
             plain_socket
                 .set_nodelay(true)
-                .map_err(|e| iceyee_error::c!(e))?;
+                .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
             /* 2 认证. */
             /* client:
              * +----+----------+----------+
@@ -420,18 +423,18 @@ impl Proxy for Socks5Proxy {
             plain_socket
                 .write(&[0x05u8, 0x02, 0x00, 0x02])
                 .await
-                .map_err(|e| iceyee_error::c!(e))?;
+                .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
             let mut buffer: [u8; 0xFFF] = [0; 0xFFF];
             let length: usize = plain_socket
                 .read(&mut buffer)
                 .await
-                .map_err(|e| iceyee_error::c!(e))?;
+                .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
             if length != 2 {
-                return Err(iceyee_error::c!("非预期"));
+                iceyee_error::bail!(BadFormat; "非预期");
             }
             if buffer[1] == 0xFF {
                 /* 认证被拒绝. */
-                return Err(iceyee_error::c!("代理认证被拒绝"));
+                iceyee_error::bail!("代理认证被拒绝");
             } else if buffer[1] == 0x02 {
                 /* USERNAME/PASSWORD.
                  * client:
@@ -459,7 +462,7 @@ impl Proxy for Socks5Proxy {
                     plain_socket
                         .write(&auth)
                         .await
-                        .map_err(|e| iceyee_error::c!(e))?;
+                        .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
                     /* server:
                      * +----+--------+
                      * |VER | STATUS |
@@ -470,11 +473,11 @@ impl Proxy for Socks5Proxy {
                     let length: usize = plain_socket
                         .read(&mut buffer)
                         .await
-                        .map_err(|e| iceyee_error::c!(e))?;
+                        .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
                     if length != 2 {
-                        return Err(iceyee_error::c!("非预期"));
+                        iceyee_error::bail!(BadFormat; "非预期");
                     } else if buffer[1] != 0x00 {
-                        return Err(iceyee_error::c!("代理认证失败"));
+                        iceyee_error::bail!("代理认证失败");
                     }
                 }
             }
@@ -509,7 +512,7 @@ impl Proxy for Socks5Proxy {
             plain_socket
                 .write(&request)
                 .await
-                .map_err(|e| iceyee_error::c!(e))?;
+                .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
             /* server:
              * +----+-----+-------+------+----------+----------+
              * |VER | REP |  RSV  | ATYP | BND.ADDR | BND.PORT |
@@ -538,30 +541,30 @@ impl Proxy for Socks5Proxy {
             let length: usize = plain_socket
                 .read(&mut buffer)
                 .await
-                .map_err(|e| iceyee_error::c!(e))?;
+                .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
             if length < 4 {
-                return Err(iceyee_error::c!("非预期"));
+                iceyee_error::bail!(BadFormat; "非预期");
             }
             if buffer[1] == 0x00 {
                 /* succeeded. */
             } else if buffer[1] == 0x01 {
-                return Err(iceyee_error::c!("general SOCKS server failure"));
+                iceyee_error::bail!("general SOCKS server failure");
             } else if buffer[1] == 0x02 {
-                return Err(iceyee_error::c!("connection not allowed by ruleset"));
+                iceyee_error::bail!("connection not allowed by ruleset");
             } else if buffer[1] == 0x03 {
-                return Err(iceyee_error::c!("Network unreachable"));
+                iceyee_error::bail!("Network unreachable");
             } else if buffer[1] == 0x04 {
-                return Err(iceyee_error::c!("Host unreachable"));
+                iceyee_error::bail!("Host unreachable");
             } else if buffer[1] == 0x05 {
-                return Err(iceyee_error::c!("Connection refused"));
+                iceyee_error::bail!("Connection refused");
             } else if buffer[1] == 0x06 {
-                return Err(iceyee_error::c!("TTL expired"));
+                iceyee_error::bail!("TTL expired");
             } else if buffer[1] == 0x07 {
-                return Err(iceyee_error::c!("Command not supported"));
+                iceyee_error::bail!("Command not supported");
             } else if buffer[1] == 0x08 {
-                return Err(iceyee_error::c!("Address type not supported"));
+                iceyee_error::bail!("Address type not supported");
             } else if buffer[1] == 0x09 {
-                return Err(iceyee_error::c!("to X’FF’ unassigned"));
+                iceyee_error::bail!("to X’FF’ unassigned");
             }
             /* 4 tls握手. */
             if !using_ssl {
@@ -569,12 +572,12 @@ impl Proxy for Socks5Proxy {
             } else {
                 self.logger.push_str("建立tls\r\n");
                 let connector = tokio_native_tls::native_tls::TlsConnector::new()
-                    .map_err(|e| iceyee_error::c!(e))?;
+                    .map_err(|e| IceyeeError::from_std(ErrorKind::Other, e))?;
                 let connector = tokio_native_tls::TlsConnector::from(connector);
                 let ssl_socket: TlsStream<TokioTcpStream> = connector
                     .connect(&target_host, plain_socket)
                     .await
-                    .map_err(|e| iceyee_error::c!(e))?;
+                    .map_err(|e| IceyeeError::from_std(ErrorKind::Other, e))?;
                 self.ssl_socket = Some(ssl_socket);
             }
             let message: String = format!("连接耗时: {}ms\r\n", iceyee_time::now() - t);
@@ -922,13 +925,13 @@ impl HttpClient {
     /// @return
     ///    - Response 返回响应.
     ///    - String logger.
-    ///    - String Error.
+    ///    - IceyeeError Error.
     pub async fn send(
         mut self,
         mut proxy: Option<WrapProxy>,
-    ) -> Result<(Response, String), String> {
+    ) -> Result<(Response, String), IceyeeError> {
         if self.url.is_none() {
-            return Err(iceyee_error::c!("未设置url."));
+            iceyee_error::bail!("未设置url.");
         }
         let t: i64 = iceyee_time::now();
         if proxy.is_none() {
@@ -943,7 +946,7 @@ impl HttpClient {
             proxy.get_logger().push_str("\r\n---- Exception ----\r\n");
             proxy
                 .get_logger()
-                .push_str(r.clone().expect_err("NEVER").as_str());
+                .push_str(r.as_ref().expect_err("NEVER").to_string().as_str());
             proxy.close().await;
         }
         let message: String = format!(
@@ -961,7 +964,7 @@ impl HttpClient {
     async fn send_(
         &mut self,
         proxy: &mut tokio::sync::MutexGuard<'_, Box<dyn Proxy>>,
-    ) -> Result<Response, String> {
+    ) -> Result<Response, IceyeeError> {
         /* 1 连接. */
         if proxy.is_closed() {
             let url: &Url = self.url.as_ref().expect("NEVER");
@@ -970,7 +973,7 @@ impl HttpClient {
                 .await?;
         }
         if proxy.is_closed() {
-            return Err(iceyee_error::c!("连接失败"));
+            iceyee_error::bail!("连接失败");
         }
         /* 2 请求头. */
         for (key, value) in [
@@ -995,7 +998,7 @@ impl HttpClient {
         proxy
             .write(header.as_bytes())
             .await
-            .map_err(|e| iceyee_error::c!(e))?;
+            .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
         /* 4 写请求正文. */
         match String::from_utf8(self.request.body.clone()) {
             Ok(s) => proxy.get_logger().push_str(&s),
@@ -1011,7 +1014,7 @@ impl HttpClient {
             proxy
                 .write(self.request.body.as_slice())
                 .await
-                .map_err(|e| iceyee_error::c!(e))?;
+                .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
         }
         /* 5 解析响应. */
         proxy.get_logger().push_str("\r\n---- Response ----\r\n");
@@ -1027,17 +1030,13 @@ impl HttpClient {
             GzipDecoder::new(response.body.as_slice())
                 .read_to_end(&mut body)
                 .await
-                .map_err(|e| iceyee_error::c!(e))?;
+                .map_err(|e| IceyeeError::from_std(e.kind().into(), e))?;
             response.body = body;
         }
-        match String::from_utf8(response.body.clone()) {
+        match response.text() {
             Ok(s) => proxy.get_logger().push_str(&s),
             Err(_) => proxy.get_logger().push_str(
-                format!(
-                    "[body is not utf-8, and has {} bytes.]",
-                    self.request.body.len()
-                )
-                .as_str(),
+                format!("[body is not text, and has {} bytes.]", response.body.len()).as_str(),
             ),
         }
         /* Connection. */
@@ -1061,17 +1060,27 @@ impl HttpClient {
     ///     .send(None)
     ///     .await;
     /// ```
-    pub async fn get(url: &str) -> Result<(Response, String), String> {
+    pub async fn get(url: &str) -> Result<(Response, String), IceyeeError> {
         return HttpClient::new()
-            .set_url(url)?
+            .set_url(url)
+            .map_err(|e| {
+                iceyee_error::inherit!(e.parse::<ErrorChain>().expect("NEVER"))
+                    .set_kind(ErrorKind::BadFormat)
+            })?
             .set_header("Connection", "close")
             .send(None)
             .await;
     }
 
+    /// 同[HttpClient::get], 返回正文的文本.
+    ///
+    /// 按照[Response::text]解码, 不支持的字符集或者解码失败时, 按照utf-8有损解码.
     pub async fn get_expect_string(url: &str) -> String {
         let (response, _) = Self::get(url).await.expect("HttpClient::get_expect_string");
-        return String::from_utf8(response.body).expect("String::from_utf8");
+        return match response.text() {
+            Ok(s) => s,
+            Err(_) => String::from_utf8_lossy(&response.body).to_string(),
+        };
     }
 }
 
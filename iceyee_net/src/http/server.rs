@@ -30,6 +30,7 @@ use crate::http::server::component::FilterHost;
 use async_compression::tokio::bufread::GzipEncoder;
 use iceyee_encoder::HexEncoder;
 use iceyee_random::Random;
+use iceyee_random::SecureRandom;
 use serde::Serialize;
 use std::collections::BTreeMap;
 use std::future::Future;
@@ -581,7 +582,7 @@ impl HttpServer {
                                 {
                                     Ok(r) => r,
                                     Err(e) => {
-                                        if e.contains("TimedOut") {
+                                        if iceyee_error::matches_kind!(e, Timeout) {
                                             iceyee_logger::debug!("超时断开连接", ip, id);
                                             break;
                                         } else {
@@ -933,7 +934,5 @@ fn ipv6_to_string(ip: std::net::Ipv6Addr) -> String {
 }
 
 fn new_session_id() -> String {
-    let a001 = HexEncoder::encode_number(Random::next());
-    let a002 = HexEncoder::encode_number(Random::next());
-    return a001 + &a002;
+    return SecureRandom::token_hex(16);
 }
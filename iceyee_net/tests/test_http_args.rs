@@ -38,3 +38,22 @@ pub fn test_args() {
     println!("{:#?}", args);
     return;
 }
+
+#[test]
+pub fn test_args_round_trip() {
+    println!("");
+    println!("测试包含'+', ' ', '&', '='的参数.");
+    let mut args: Args = Args::default();
+    args.add("a b", "1+1=2");
+    args.add("c&d", "PrW4rLRM-K40GMA77lYUD+fvXc8=");
+    let s: String = args.to_string();
+    println!("{}", s);
+    assert_eq!(s, "?a%20b=1%2B1%3D2&c%26d=PrW4rLRM-K40GMA77lYUD%2BfvXc8%3D");
+    let args: Args = s.parse::<Args>().expect("NEVER");
+    assert_eq!(args.get("a b"), &vec!["1+1=2".to_string()]);
+    assert_eq!(
+        args.get("c&d"),
+        &vec!["PrW4rLRM-K40GMA77lYUD+fvXc8=".to_string()]
+    );
+    return;
+}
@@ -38,6 +38,38 @@ pub async fn test_httpclient_no_proxy() {
         .await;
 }
 
+#[tokio::test]
+pub async fn test_httpclient_timeout() {
+    println!("");
+    println!("服务器接受连接, 但不响应, 期望超时.");
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("TcpListener::bind");
+    let port: u16 = listener.local_addr().expect("local_addr").port();
+    tokio::task::spawn(async move {
+        let (socket, _) = listener.accept().await.expect("TcpListener::accept");
+        tokio::time::sleep(std::time::Duration::from_millis(2_000)).await;
+        drop(socket);
+    });
+    let e = HttpClient::new()
+        .set_timeout(200)
+        .set_url(format!("http://127.0.0.1:{}/", port))
+        .expect("HttpClient::set_url")
+        .send(None)
+        .await
+        .expect_err("HttpClient::send");
+    println!("{}", e);
+    assert!(iceyee_error::matches_kind!(e, Timeout));
+    println!("url格式错误.");
+    let e = HttpClient::get("127.0.0.1")
+        .await
+        .expect_err("HttpClient::get");
+    println!("{}", e);
+    assert!(iceyee_error::matches_kind!(e, BadFormat));
+    assert_eq!(e.frames().len(), 2);
+    assert_eq!(e.frames()[1].kind, iceyee_error::FrameKind::Inherit);
+}
+
 // #[tokio::test]
 #[allow(unused_variables)]
 pub async fn test_httpclient_repeated_use() {
@@ -73,3 +105,35 @@ pub async fn test_httpclient_repeated_use() {
         .await
         .expect("433");
 }
+
+#[tokio::test]
+pub async fn test_httpclient_get_expect_string() {
+    println!("");
+    println!("不支持的字符集, 按照utf-8有损解码.");
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("TcpListener::bind");
+    let port: u16 = listener.local_addr().expect("local_addr").port();
+    tokio::task::spawn(async move {
+        use tokio::io::AsyncReadExt;
+        use tokio::io::AsyncWriteExt;
+        let (mut socket, _) = listener.accept().await.expect("TcpListener::accept");
+        let mut buffer: [u8; 0xFFF] = [0; 0xFFF];
+        let _ = socket.read(&mut buffer).await;
+        let body: &[u8] = b"hello \xFF";
+        let header: String = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; charset=shift_jis\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len()
+        );
+        socket
+            .write_all(header.as_bytes())
+            .await
+            .expect("TcpStream::write_all");
+        socket.write_all(body).await.expect("TcpStream::write_all");
+    });
+    let url: String = format!("http://127.0.0.1:{}/", port);
+    assert_eq!(
+        HttpClient::get_expect_string(&url).await,
+        "hello \u{FFFD}".to_string()
+    );
+}
@@ -52,4 +52,18 @@ hello\
         a001.to_string(),
         String::from_utf8(a001.body.clone()).expect("String::from_utf8()")
     );
+    println!("");
+    println!("Request::read_from()异常的类型.");
+    let s: &str = "GET / HTTP/1.1\r\nHost: www.baidu.com\r\n";
+    let e = Request::read_from(s.as_bytes(), None).await.unwrap_err();
+    println!("{}", e);
+    assert!(iceyee_error::matches_kind!(e, Eof));
+    let (_a002, a003) = tokio::io::duplex(0xFF);
+    let e = Request::read_from(a003, Some(100)).await.unwrap_err();
+    println!("{}", e);
+    assert!(iceyee_error::matches_kind!(e, Timeout));
+    let s: &str = "GET / HTTP/1.1\r\nContent-Length: x\r\n\r\n";
+    let e = Request::read_from(s.as_bytes(), None).await.unwrap_err();
+    println!("{}", e);
+    assert!(iceyee_error::matches_kind!(e, BadFormat));
 }
@@ -70,3 +70,61 @@ world\r\n\
         String::from_utf8(a001.body.clone()).expect("String::from_utf8()"),
     );
 }
+
+#[test]
+pub fn test_response_text() {
+    println!("");
+    println!("没有charset, 按utf-8.");
+    let mut response: Response = Response::default();
+    response.body = "你好".as_bytes().to_vec();
+    assert_eq!(response.charset(), None);
+    assert_eq!(response.text().expect("NEVER"), "你好");
+    response
+        .header
+        .insert("Content-Type".to_string(), vec!["text/html".to_string()]);
+    assert_eq!(response.charset(), None);
+    assert_eq!(response.text().expect("NEVER"), "你好");
+    println!("GBK.");
+    let mut response: Response = Response::default();
+    response.header.insert(
+        "content-type".to_string(),
+        vec!["text/html; Charset=\"GBK\"".to_string()],
+    );
+    response.body = vec![0xC4, 0xE3, 0xBA, 0xC3];
+    assert_eq!(response.charset(), Some("gbk".to_string()));
+    assert_eq!(response.text().expect("NEVER"), "你好");
+    response.body = vec![0xC4, 0xE3, 0xBA];
+    assert!(response.text().is_err());
+    println!("ISO-8859-1.");
+    let mut response: Response = Response::default();
+    response.header.insert(
+        "Content-Type".to_string(),
+        vec!["text/plain;charset=ISO-8859-1".to_string()],
+    );
+    response.body = vec![b'a', 0xE9];
+    assert_eq!(response.text().expect("NEVER"), "aé");
+    println!("US-ASCII, 不接受0x80以上的字节.");
+    let mut response: Response = Response::default();
+    response.header.insert(
+        "Content-Type".to_string(),
+        vec!["text/plain; charset=us-ascii".to_string()],
+    );
+    response.body = vec![b'a', b'b'];
+    assert_eq!(response.text().expect("NEVER"), "ab");
+    response.body = vec![b'a', 0xE9];
+    assert_eq!(
+        response.text().map_err(|x| x.contains("出现非ascii字符")),
+        Err(true)
+    );
+    println!("不支持的字符集.");
+    let mut response: Response = Response::default();
+    response.header.insert(
+        "Content-Type".to_string(),
+        vec!["text/plain; charset=koi8-r".to_string()],
+    );
+    assert_eq!(
+        response.text().map_err(|x| x.contains("不支持的字符集")),
+        Err(true)
+    );
+    return;
+}
@@ -88,3 +88,20 @@ pub fn test_url() {
     println!("{:?}", "https://www.baidu.com:999999".parse::<Url>());
     return;
 }
+
+#[test]
+pub fn test_url_idna() {
+    println!("");
+    println!("测试国际化域名.");
+    let url: Url = "https://例子.中国:8443/p?a=1".parse::<Url>().unwrap();
+    println!("{:#?}", url);
+    assert_eq!(url.host, "xn--fsqu00a.xn--fiqs8s");
+    assert_eq!(url.port, 8443);
+    assert_eq!(url.path, "/p");
+    let url: Url = "http://WWW.Bücher.de".parse::<Url>().unwrap();
+    println!("{:#?}", url);
+    assert_eq!(url.host, "www.xn--bcher-kva.de");
+    assert_eq!(url.path, "/");
+    assert!("http://a..b/".parse::<Url>().is_err());
+    return;
+}
@@ -4,106 +4,1187 @@
 // *  Git: https://github.com/iceyee                *
 // **************************************************
 //
+
+//! 随机数.
+//!
+//! - [Rng], 可设置种子的伪随机数生成器, 算法是xoshiro256**, 同一个种子在任何线程都得到相同的序列.
+//! - [Random], 静态函数, 使用线程变量里的[Rng].
+//! - [SecureRng], 密码学安全的随机数生成器, 算法是ChaCha20, 种子来自操作系统.
+//! - [SecureRandom], 静态函数, 使用线程变量里的[SecureRng], 用于会话id, CSRF令牌, 密钥等.
+//! - [Generator], 两者共用的接口, 提供无偏差的区间, 浮点数, 以及伯努利, 正态, 指数, 泊松分布,
+//!   还有打乱, 随机取元素和蓄水池抽样.
+//! - [AliasTable], 加权随机.
+//! - [Uuid], [Ulid], [Snowflake], 唯一id, 同一毫秒内也是递增的.
+//!
+//! # Example
+//! ```
+//! use iceyee_random::Generator;
+//! use iceyee_random::Rng;
+//!
+//! let mut a: Rng = Rng::from_seed(0xFFFF);
+//! let mut b: Rng = Rng::from_seed(0xFFFF);
+//! assert_eq!(a.next_u64(), b.next_u64());
+//! let state: [u8; 32] = a.state();
+//! let mut c: Rng = Rng::from_state(&state).expect("NEVER");
+//! assert_eq!(a.next_u64(), c.next_u64());
+//! ```
+
 /* Use. */
 
-use std::cell::Cell;
+use iceyee_encoder::Base64Config;
+use iceyee_encoder::Base64Encoder;
+use iceyee_encoder::HexEncoder;
+use serde::Deserialize;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering::SeqCst;
 use std::time::SystemTime;
 
 thread_local! {
-    static SEED: Cell<u64> = Cell::new(0);
-    static THREAD_ID: Cell<u64> = Cell::new(0);
+    static RNG: RefCell<Rng> = RefCell::new(Rng::new());
+    static SECURE_RNG: RefCell<SecureRng> = RefCell::new(SecureRng::new());
 }
 
 /* Enum. */
 
 /* Trait. */
 
+/// 随机数生成器.
+pub trait Generator {
+    /// 下一个随机数.
+    fn next_u64(&mut self) -> u64;
+
+    /// 下一个随机数, 取[Generator::next_u64]的高32位.
+    fn next_u32(&mut self) -> u32 {
+        return (self.next_u64() >> 32) as u32;
+    }
+
+    /// 用随机数填满output.
+    fn fill_bytes(&mut self, output: &mut [u8]) {
+        for chunk in output.chunks_mut(8) {
+            let bytes: [u8; 8] = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+        return;
+    }
+
+    /// 均匀分布在\[0,max)的随机数, 没有取模带来的偏差, max为0时返回0.
+    fn next_less_than(&mut self, max: u64) -> u64 {
+        if max == 0 {
+            return 0;
+        }
+        /* Lemire, 乘法取高位, 拒绝落在不完整区间的低位. */
+        let mut m: u128 = self.next_u64() as u128 * max as u128;
+        if (m as u64) < max {
+            let threshold: u64 = max.wrapping_neg() % max;
+            while (m as u64) < threshold {
+                m = self.next_u64() as u128 * max as u128;
+            }
+        }
+        return (m >> 64) as u64;
+    }
+
+    /// 均匀分布在\[low,high)的随机数, low>=high时返回low.
+    fn range_u64(&mut self, low: u64, high: u64) -> u64 {
+        if high <= low {
+            return low;
+        }
+        return low + self.next_less_than(high - low);
+    }
+
+    /// 均匀分布在\[low,high\]的随机数, low>high时返回low.
+    fn range_inclusive_u64(&mut self, low: u64, high: u64) -> u64 {
+        if high < low {
+            return low;
+        }
+        if high - low == u64::MAX {
+            return self.next_u64();
+        }
+        return low + self.next_less_than(high - low + 1);
+    }
+
+    /// 均匀分布在\[low,high)的随机数, low>=high时返回low.
+    fn range_i64(&mut self, low: i64, high: i64) -> i64 {
+        if high <= low {
+            return low;
+        }
+        let span: u64 = high.wrapping_sub(low) as u64;
+        return low.wrapping_add(self.next_less_than(span) as i64);
+    }
+
+    /// 均匀分布在\[low,high\]的随机数, low>high时返回low.
+    fn range_inclusive_i64(&mut self, low: i64, high: i64) -> i64 {
+        if high < low {
+            return low;
+        }
+        let span: u64 = high.wrapping_sub(low) as u64;
+        if span == u64::MAX {
+            return self.next_u64() as i64;
+        }
+        return low.wrapping_add(self.next_less_than(span + 1) as i64);
+    }
+
+    /// 均匀分布在\[0,1)的浮点数, 精度2^-53.
+    fn next_f64(&mut self) -> f64 {
+        return (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64);
+    }
+
+    /// 均匀分布在\[0,1)的浮点数, 精度2^-24.
+    fn next_f32(&mut self) -> f32 {
+        return (self.next_u64() >> 40) as f32 * (1.0 / (1u32 << 24) as f32);
+    }
+
+    /// 伯努利分布, 以概率p返回true, p<=0总是false, p>=1总是true.
+    fn bernoulli(&mut self, p: f64) -> bool {
+        return self.next_f64() < p;
+    }
+
+    /// 正态分布, Marsaglia极坐标法.
+    fn normal(&mut self, mean: f64, std_dev: f64) -> f64 {
+        loop {
+            let u: f64 = self.next_f64() * 2.0 - 1.0;
+            let v: f64 = self.next_f64() * 2.0 - 1.0;
+            let s: f64 = u * u + v * v;
+            if 0.0 < s && s < 1.0 {
+                return mean + std_dev * u * (-2.0 * s.ln() / s).sqrt();
+            }
+        }
+    }
+
+    /// 指数分布, lambda是速率, 期望是1/lambda.
+    fn exponential(&mut self, lambda: f64) -> f64 {
+        return -(1.0 - self.next_f64()).ln() / lambda;
+    }
+
+    /// 泊松分布, lambda是期望, lambda<=0时返回0.
+    ///
+    /// lambda较小时逐个相乘, 较大时用Hörmann的PTRS, 时间都是常数级.
+    fn poisson(&mut self, lambda: f64) -> u64 {
+        if lambda.is_nan() || lambda <= 0.0 {
+            return 0;
+        }
+        if lambda < 10.0 {
+            let limit: f64 = (-lambda).exp();
+            let mut product: f64 = self.next_f64();
+            let mut k: u64 = 0;
+            while limit < product {
+                product *= self.next_f64();
+                k += 1;
+            }
+            return k;
+        }
+        let slam: f64 = lambda.sqrt();
+        let loglam: f64 = lambda.ln();
+        let b: f64 = 0.931 + 2.53 * slam;
+        let a: f64 = -0.059 + 0.02483 * b;
+        let invalpha: f64 = 1.1239 + 1.1328 / (b - 3.4);
+        let vr: f64 = 0.9277 - 3.6224 / (b - 2.0);
+        loop {
+            let u: f64 = self.next_f64() - 0.5;
+            let v: f64 = self.next_f64();
+            let us: f64 = 0.5 - u.abs();
+            let k: f64 = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+            if 0.07 <= us && v <= vr {
+                return k as u64;
+            }
+            if k < 0.0 || (us < 0.013 && us < v) {
+                continue;
+            }
+            if v.ln() + invalpha.ln() - (a / (us * us) + b).ln()
+                <= -lambda + k * loglam - log_factorial(k)
+            {
+                return k as u64;
+            }
+        }
+    }
+
+    /// 随机打乱, Fisher-Yates.
+    fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for x in (1..slice.len()).rev() {
+            let y: usize = self.next_less_than(x as u64 + 1) as usize;
+            slice.swap(x, y);
+        }
+        return;
+    }
+
+    /// 随机取一个元素, 空的返回[None].
+    fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            return None;
+        }
+        return Some(&slice[self.next_less_than(slice.len() as u64) as usize]);
+    }
+
+    /// 不重复地随机取amount个元素, 顺序也是随机的, 不够则全部取出.
+    fn choose_multiple<'a, T>(&mut self, slice: &'a [T], amount: usize) -> Vec<&'a T> {
+        let amount: usize = amount.min(slice.len());
+        let mut indexes: Vec<usize> = (0..slice.len()).collect();
+        for x in 0..amount {
+            let y: usize = self.range_u64(x as u64, slice.len() as u64) as usize;
+            indexes.swap(x, y);
+        }
+        return indexes[..amount].iter().map(|x| &slice[*x]).collect();
+    }
+
+    /// 蓄水池抽样, 只遍历一次, 不需要知道长度, 不重复地取amount个元素, 不够则全部取出.
+    ///
+    /// 结果的顺序不是随机的, 需要的话再[Generator::shuffle].
+    fn reservoir_sample<I>(&mut self, iter: I, amount: usize) -> Vec<I::Item>
+    where
+        I: IntoIterator,
+    {
+        let mut output: Vec<I::Item> = Vec::with_capacity(amount);
+        for (index, item) in iter.into_iter().enumerate() {
+            if index < amount {
+                output.push(item);
+                continue;
+            }
+            let x: usize = self.next_less_than(index as u64 + 1) as usize;
+            if x < amount {
+                output[x] = item;
+            }
+        }
+        return output;
+    }
+}
+
 /* Struct. */
 
+/// 伪随机数生成器, xoshiro256**, 周期2^256-1.
+///
+/// 结果只取决于种子, 与线程无关. 状态可以通过[Rng::state]和[Rng::from_state]保存和恢复,
+/// 也可以用serde序列化, 格式是4个u64的数组, 反序列化时拒绝全0的状态.
+///
+/// 不能用于密码学.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "[u64; 4]", try_from = "[u64; 4]")]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+impl From<Rng> for [u64; 4] {
+    fn from(value: Rng) -> Self {
+        return value.state;
+    }
+}
+
+/// - @exception 无效的状态, 全0.
+impl TryFrom<[u64; 4]> for Rng {
+    type Error = String;
+
+    fn try_from(value: [u64; 4]) -> Result<Self, Self::Error> {
+        iceyee_error::ensure!(value != [0; 4], "无效的状态");
+        return Ok(Self { state: value });
+    }
+}
+
+impl std::default::Default for Rng {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl Generator for Rng {
+    fn next_u64(&mut self) -> u64 {
+        let s: &mut [u64; 4] = &mut self.state;
+        let result: u64 = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t: u64 = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        return result;
+    }
+}
+
+impl Rng {
+    /// 用时间, 线程id和计数器作为种子, 每次调用都不一样.
+    pub fn new() -> Self {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let time: u64 = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+        let count: u64 = COUNTER.fetch_add(1, SeqCst);
+        let mut seed: [u8; 24] = [0; 24];
+        seed[0..8].copy_from_slice(&time.to_le_bytes());
+        seed[8..16].copy_from_slice(&get_thread_id().to_le_bytes());
+        seed[16..24].copy_from_slice(&count.to_le_bytes());
+        return Self::from_bytes(&seed);
+    }
+
+    /// 指定种子, 种子经过splitmix64扩展成256位状态.
+    pub fn from_seed(seed: u64) -> Self {
+        let mut x: u64 = seed;
+        let mut state: [u64; 4] = [0; 4];
+        for value in state.iter_mut() {
+            *value = splitmix64(&mut x);
+        }
+        return Self::from_words(state);
+    }
+
+    /// 指定种子, 任意长度的字节.
+    pub fn from_bytes(seed: &[u8]) -> Self {
+        let mut x: u64 = seed.len() as u64;
+        let mut state: [u64; 4] = [0; 4];
+        for value in state.iter_mut() {
+            *value = splitmix64(&mut x);
+        }
+        for (index, chunk) in seed.chunks(8).enumerate() {
+            let mut bytes: [u8; 8] = [0; 8];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            x ^= u64::from_le_bytes(bytes);
+            state[index % 4] ^= splitmix64(&mut x);
+        }
+        return Self::from_words(state);
+    }
+
+    /// 保存状态, 256位, 小端.
+    pub fn state(&self) -> [u8; 32] {
+        let mut output: [u8; 32] = [0; 32];
+        for (index, value) in self.state.iter().enumerate() {
+            output[index * 8..index * 8 + 8].copy_from_slice(&value.to_le_bytes());
+        }
+        return output;
+    }
+
+    /// 恢复[Rng::state]保存的状态, 全0是无效状态, 返回[None].
+    pub fn from_state(state: &[u8; 32]) -> Option<Self> {
+        let mut words: [u64; 4] = [0; 4];
+        for (index, value) in words.iter_mut().enumerate() {
+            let mut bytes: [u8; 8] = [0; 8];
+            bytes.copy_from_slice(&state[index * 8..index * 8 + 8]);
+            *value = u64::from_le_bytes(bytes);
+        }
+        if words == [0; 4] {
+            return None;
+        }
+        return Some(Self { state: words });
+    }
+
+    fn from_words(mut state: [u64; 4]) -> Self {
+        if state == [0; 4] {
+            state[0] = 1;
+        }
+        return Self { state: state };
+    }
+}
+
+/// 密码学安全的随机数生成器, ChaCha20.
+///
+/// 使用Bernstein原始的布局, 64位块计数器和64位nonce, nonce固定为0, 256位密钥作为种子.
+/// 与RFC 8439(32位计数器, 96位nonce)只是计数器和nonce的划分不同, nonce为0时前2^32个块的输出相同.
+///
+/// 不实现[Clone], 避免两处输出相同的序列.
+pub struct SecureRng {
+    key: [u32; 8],
+    counter: u64,
+    buffer: [u8; 64],
+    index: usize,
+}
+
+impl std::default::Default for SecureRng {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl std::fmt::Debug for SecureRng {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f.debug_struct("SecureRng").finish_non_exhaustive();
+    }
+}
+
+impl Generator for SecureRng {
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes: [u8; 8] = [0; 8];
+        self.fill_bytes(&mut bytes);
+        return u64::from_le_bytes(bytes);
+    }
+
+    fn fill_bytes(&mut self, output: &mut [u8]) {
+        let mut offset: usize = 0;
+        while offset < output.len() {
+            if self.index == 64 {
+                self.refill();
+            }
+            let length: usize = (output.len() - offset).min(64 - self.index);
+            output[offset..offset + length]
+                .copy_from_slice(&self.buffer[self.index..self.index + length]);
+            self.buffer[self.index..self.index + length].fill(0);
+            self.index += length;
+            offset += length;
+        }
+        return;
+    }
+}
+
+impl SecureRng {
+    const CONSTANT: [u32; 4] = [0x61707865, 0x3320646E, 0x79622D32, 0x6B206574];
+
+    /// 用操作系统的随机数作为种子.
+    ///
+    /// 读取失败会panic, 见[SecureRng::try_new].
+    pub fn new() -> Self {
+        return Self::try_new().expect("SecureRng::try_new");
+    }
+
+    /// 用操作系统的随机数作为种子.
+    ///
+    /// - @exception 读取操作系统的随机数失败.
+    pub fn try_new() -> Result<Self, String> {
+        let mut seed: [u8; 32] = [0; 32];
+        fill_os_random(&mut seed)?;
+        let rng: Self = Self::from_seed(&seed);
+        seed.fill(0);
+        return Ok(rng);
+    }
+
+    /// 指定256位密钥, 相同的密钥得到相同的序列, 用于测试.
+    pub fn from_seed(seed: &[u8; 32]) -> Self {
+        let mut key: [u32; 8] = [0; 8];
+        for (index, value) in key.iter_mut().enumerate() {
+            let mut bytes: [u8; 4] = [0; 4];
+            bytes.copy_from_slice(&seed[index * 4..index * 4 + 4]);
+            *value = u32::from_le_bytes(bytes);
+        }
+        return Self {
+            key: key,
+            counter: 0,
+            buffer: [0; 64],
+            index: 64,
+        };
+    }
+
+    /// 随机字节.
+    pub fn token_bytes(&mut self, length: usize) -> Vec<u8> {
+        let mut output: Vec<u8> = vec![0; length];
+        self.fill_bytes(&mut output);
+        return output;
+    }
+
+    /// length个随机字节, 编码成小写的十六进制, 长度是2*length.
+    pub fn token_hex(&mut self, length: usize) -> String {
+        return HexEncoder::encode(&self.token_bytes(length)).to_ascii_lowercase();
+    }
+
+    /// length个随机字节, 按[Base64Config::URL_SAFE_NO_PAD]编码, 可以直接放在url和cookie里.
+    pub fn token_urlsafe(&mut self, length: usize) -> String {
+        return Base64Encoder::encode_with(
+            &self.token_bytes(length),
+            &Base64Config::URL_SAFE_NO_PAD,
+        );
+    }
+
+    fn refill(&mut self) {
+        let mut state: [u32; 16] = [0; 16];
+        state[0..4].copy_from_slice(&Self::CONSTANT);
+        state[4..12].copy_from_slice(&self.key);
+        state[12] = self.counter as u32;
+        state[13] = (self.counter >> 32) as u32;
+        let mut x: [u32; 16] = state;
+        for _ in 0..10 {
+            quarter_round(&mut x, 0, 4, 8, 12);
+            quarter_round(&mut x, 1, 5, 9, 13);
+            quarter_round(&mut x, 2, 6, 10, 14);
+            quarter_round(&mut x, 3, 7, 11, 15);
+            quarter_round(&mut x, 0, 5, 10, 15);
+            quarter_round(&mut x, 1, 6, 11, 12);
+            quarter_round(&mut x, 2, 7, 8, 13);
+            quarter_round(&mut x, 3, 4, 9, 14);
+        }
+        for index in 0..16 {
+            let value: u32 = x[index].wrapping_add(state[index]);
+            self.buffer[index * 4..index * 4 + 4].copy_from_slice(&value.to_le_bytes());
+        }
+        self.counter = self.counter.wrapping_add(1);
+        self.index = 0;
+        return;
+    }
+}
+
+impl std::ops::Drop for SecureRng {
+    fn drop(&mut self) {
+        self.key.fill(0);
+        self.buffer.fill(0);
+        return;
+    }
+}
+
+/// 加权随机, Vose的别名方法, 构造O(n), 每次抽样O(1).
+///
+/// 适合权重固定, 抽样很多次的场景, 例如按权重轮换代理.
+///
+/// # Example
+/// ```
+/// use iceyee_random::AliasTable;
+/// use iceyee_random::Random;
+/// use iceyee_random::Rng;
+///
+/// let proxies: [&str; 3] = ["a", "b", "c"];
+/// let table: AliasTable = AliasTable::new(&[5.0, 3.0, 2.0]).expect("NEVER");
+/// let index: usize = table.sample(&mut Rng::from_seed(1));
+/// assert!(index < proxies.len());
+/// let index: usize = Random::with(|rng| table.sample(rng));
+/// assert!(index < proxies.len());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AliasTable {
+    probability: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    /// 权重不需要归一化, 为0的永远不会被选中.
+    ///
+    /// - @exception 权重为空.
+    /// - @exception 无效的权重, 负数, NaN, 无穷大, 或者总和为0.
+    pub fn new(weights: &[f64]) -> Result<Self, String> {
+        iceyee_error::ensure!(!weights.is_empty(), "权重为空");
+        iceyee_error::ensure!(
+            weights.iter().all(|x| x.is_finite() && 0.0 <= *x),
+            "无效的权重"
+        );
+        let sum: f64 = weights.iter().sum();
+        iceyee_error::ensure!(0.0 < sum && sum.is_finite(), "无效的权重", sum);
+        let n: usize = weights.len();
+        let mut probability: Vec<f64> = weights.iter().map(|x| x * n as f64 / sum).collect();
+        let mut alias: Vec<usize> = (0..n).collect();
+        let mut small: Vec<usize> = Vec::new();
+        let mut large: Vec<usize> = Vec::new();
+        for (index, value) in probability.iter().enumerate() {
+            if *value < 1.0 {
+                small.push(index);
+            } else {
+                large.push(index);
+            }
+        }
+        while let (Some(s), Some(l)) = (small.last().copied(), large.last().copied()) {
+            small.pop();
+            alias[s] = l;
+            probability[l] = probability[l] + probability[s] - 1.0;
+            if probability[l] < 1.0 {
+                large.pop();
+                small.push(l);
+            }
+        }
+        /* 剩下的只差浮点误差, 当作1. */
+        for index in small.into_iter().chain(large) {
+            probability[index] = 1.0;
+        }
+        return Ok(Self {
+            probability: probability,
+            alias: alias,
+        });
+    }
+
+    /// 权重的个数.
+    pub fn len(&self) -> usize {
+        return self.probability.len();
+    }
+
+    /// 与[AliasTable::len]成对提供, 方便按容器的习惯使用; [AliasTable::new]不接受空的权重, 所以总是false.
+    pub fn is_empty(&self) -> bool {
+        return self.probability.is_empty();
+    }
+
+    /// 按权重随机返回一个下标.
+    pub fn sample<G>(&self, generator: &mut G) -> usize
+    where
+        G: Generator,
+    {
+        let index: usize = generator.next_less_than(self.probability.len() as u64) as usize;
+        if generator.next_f64() < self.probability[index] {
+            return index;
+        }
+        return self.alias[index];
+    }
+
+    /// 按权重随机取一个元素, slice的长度应该与权重的个数相同, 否则返回[None].
+    pub fn choose<'a, T, G>(&self, generator: &mut G, slice: &'a [T]) -> Option<&'a T>
+    where
+        G: Generator,
+    {
+        if slice.len() != self.len() {
+            return None;
+        }
+        return Some(&slice[self.sample(generator)]);
+    }
+}
+
+/// UUID, RFC 9562, 支持生成v4和v7.
+///
+/// 字符串是小写的'8-4-4-4-12'格式, 解析时也接受不带'-'的32位十六进制, 不区分大小写.
+///
+/// # Example
+/// ```
+/// use iceyee_random::Uuid;
+///
+/// let a: Uuid = Uuid::new_v7();
+/// let b: Uuid = Uuid::new_v7();
+/// assert!(a < b);
+/// assert_eq!(a.version(), 7);
+/// assert_eq!(a.to_string().parse::<Uuid>(), Ok(a));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Uuid(u128);
+
+impl std::fmt::Display for Uuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let x: u128 = self.0;
+        return write!(
+            f,
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            x >> 96,
+            (x >> 80) & 0xFFFF,
+            (x >> 64) & 0xFFFF,
+            (x >> 48) & 0xFFFF,
+            x & 0xFFFF_FFFF_FFFF
+        );
+    }
+}
+
+/// - @exception 错误的格式.
+impl std::str::FromStr for Uuid {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex: String = match s.len() {
+            32 => s.to_string(),
+            36 => {
+                for index in [8, 13, 18, 23] {
+                    iceyee_error::ensure!(s.as_bytes()[index] == b'-', "错误的格式", s);
+                }
+                s.replace('-', "")
+            }
+            _ => return Err(iceyee_error::a!("错误的格式", s)),
+        };
+        iceyee_error::ensure!(
+            hex.len() == 32 && hex.bytes().all(|x| x.is_ascii_hexdigit()),
+            "错误的格式",
+            s
+        );
+        let value: u128 =
+            u128::from_str_radix(&hex, 16).map_err(|_| iceyee_error::a!("错误的格式", s))?;
+        return Ok(Self(value));
+    }
+}
+
+impl Uuid {
+    /// 全0.
+    pub fn nil() -> Self {
+        return Self(0);
+    }
+
+    pub fn from_u128(value: u128) -> Self {
+        return Self(value);
+    }
+
+    pub fn as_u128(&self) -> u128 {
+        return self.0;
+    }
+
+    /// 大端.
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        return Self(u128::from_be_bytes(bytes));
+    }
+
+    /// 大端.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        return self.0.to_be_bytes();
+    }
+
+    /// 版本号, 4或者7, 其它来源的UUID也可能是别的值.
+    pub fn version(&self) -> u8 {
+        return ((self.0 >> 76) & 0xF) as u8;
+    }
+
+    /// v7的时间戳, 单位:毫秒, 其它版本返回[None].
+    pub fn timestamp(&self) -> Option<i64> {
+        if self.version() != 7 {
+            return None;
+        }
+        return Some((self.0 >> 80) as i64);
+    }
+
+    /// v4, 122位随机数, 来自[SecureRandom].
+    pub fn new_v4() -> Self {
+        return SecureRandom::with(Self::new_v4_with);
+    }
+
+    /// v4, 122位随机数, 来自指定的生成器.
+    pub fn new_v4_with<G>(generator: &mut G) -> Self
+    where
+        G: Generator,
+    {
+        let mut bytes: [u8; 16] = [0; 16];
+        generator.fill_bytes(&mut bytes);
+        return Self::with_version(u128::from_be_bytes(bytes), 4);
+    }
+
+    /// v7, 48位毫秒时间戳, 12位计数器, 62位随机数, 按时间排序.
+    ///
+    /// 同一毫秒内计数器递增, 计数器用完或者时钟回拨时沿用上一个时间戳并往后借, 所以在进程内严格递增.
+    pub fn new_v7() -> Self {
+        static STATE: Mutex<(i64, u128)> = Mutex::new((0, 0));
+        let now: i64 = iceyee_time::now();
+        let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
+        let (last, counter) = &mut *state;
+        if *last < now {
+            *last = now;
+            *counter = SecureRandom::next() as u128 & 0x7FF;
+        } else if *counter < 0xFFF {
+            *counter += 1;
+        } else {
+            *last += 1;
+            *counter = SecureRandom::next() as u128 & 0x7FF;
+        }
+        let timestamp: u128 = (*last as u128) & 0xFFFF_FFFF_FFFF;
+        let value: u128 = (timestamp << 80) | (*counter << 64) | SecureRandom::next() as u128;
+        return Self::with_version(value, 7);
+    }
+
+    fn with_version(value: u128, version: u128) -> Self {
+        let value: u128 = (value & !(0xF << 76)) | (version << 76);
+        let value: u128 = (value & !(0x3 << 62)) | (0x2 << 62);
+        return Self(value);
+    }
+}
+
+/// ULID, 48位毫秒时间戳和80位随机数, 字符串是26个字符的Crockford Base32.
+///
+/// 同一毫秒内随机数部分加1, 所以在进程内严格递增.
+///
+/// # Example
+/// ```
+/// use iceyee_random::Ulid;
+///
+/// let a: Ulid = Ulid::new();
+/// let b: Ulid = Ulid::new();
+/// assert!(a < b);
+/// assert!(a.to_string() < b.to_string());
+/// assert_eq!(a.to_string().parse::<Ulid>(), Ok(a));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ulid(u128);
+
+impl std::fmt::Display for Ulid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output: [u8; 26] = [0; 26];
+        for (index, value) in output.iter_mut().enumerate() {
+            let shift: usize = 5 * (25 - index);
+            *value = Self::TABLE[((self.0 >> shift) & 0x1F) as usize];
+        }
+        return f.write_str(std::str::from_utf8(&output).expect("NEVER"));
+    }
+}
+
+/// 不区分大小写, 'I'和'L'当作'1', 'O'当作'0'.
+///
+/// - @exception 错误的格式.
+/// - @exception 数值溢出, 第一个字符大于'7'.
+impl std::str::FromStr for Ulid {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        iceyee_error::ensure!(s.len() == 26, "错误的格式", s);
+        let mut value: u128 = 0;
+        for (index, x) in s.bytes().enumerate() {
+            let digit: u8 = match x.to_ascii_uppercase() {
+                b'O' => 0,
+                b'I' | b'L' => 1,
+                x => match Self::TABLE.iter().position(|y| *y == x) {
+                    Some(digit) => digit as u8,
+                    None => return Err(iceyee_error::a!("错误的格式", s)),
+                },
+            };
+            iceyee_error::ensure!(index != 0 || digit < 8, "数值溢出", s);
+            value = (value << 5) | digit as u128;
+        }
+        return Ok(Self(value));
+    }
+}
+
+impl Ulid {
+    const TABLE: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+    const RANDOM_MASK: u128 = (1 << 80) - 1;
+
+    /// 当前时间, 随机数来自[SecureRandom].
+    pub fn new() -> Self {
+        static STATE: Mutex<(i64, u128)> = Mutex::new((0, 0));
+        let now: i64 = iceyee_time::now();
+        let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
+        let (last, random) = &mut *state;
+        if *last < now || *random == Self::RANDOM_MASK {
+            *last = (*last + 1).max(now);
+            let mut bytes: [u8; 16] = [0; 16];
+            SecureRandom::fill_bytes(&mut bytes[6..]);
+            /* 最高位留0, 保证同一毫秒内至少还能递增2^79次. */
+            *random = u128::from_be_bytes(bytes) >> 1;
+        } else {
+            *random += 1;
+        }
+        return Self::from_parts(*last, *random);
+    }
+
+    /// timestamp取低48位, random取低80位.
+    pub fn from_parts(timestamp: i64, random: u128) -> Self {
+        let timestamp: u128 = (timestamp as u128) & 0xFFFF_FFFF_FFFF;
+        return Self((timestamp << 80) | (random & Self::RANDOM_MASK));
+    }
+
+    pub fn from_u128(value: u128) -> Self {
+        return Self(value);
+    }
+
+    pub fn as_u128(&self) -> u128 {
+        return self.0;
+    }
+
+    /// 大端.
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        return Self(u128::from_be_bytes(bytes));
+    }
+
+    /// 大端.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        return self.0.to_be_bytes();
+    }
+
+    /// 时间戳, 单位:毫秒.
+    pub fn timestamp(&self) -> i64 {
+        return (self.0 >> 80) as i64;
+    }
+
+    /// 低80位的随机数.
+    pub fn random(&self) -> u128 {
+        return self.0 & Self::RANDOM_MASK;
+    }
+}
+
+/// 雪花算法, 64位id, 1位0, 41位毫秒时间戳, 10位机器id, 12位序列号.
+///
+/// 时间戳相对于epoch, 默认是[Snowflake::DEFAULT_EPOCH]. 同一毫秒内序列号递增, 序列号用完或者时钟回拨时
+/// 沿用上一个时间戳并往后借, 不会阻塞, 所以同一个生成器的id严格递增.
+///
+/// # Example
+/// ```
+/// use iceyee_random::Snowflake;
+///
+/// let snowflake: Snowflake = Snowflake::new(1).expect("NEVER");
+/// let a: u64 = snowflake.next_id();
+/// let b: u64 = snowflake.next_id();
+/// assert!(a < b);
+/// assert_eq!(snowflake.decompose(a).1, 1);
+/// ```
+#[derive(Debug)]
+pub struct Snowflake {
+    worker_id: u64,
+    epoch: i64,
+    state: Mutex<(i64, u64)>,
+}
+
+impl Snowflake {
+    /// 2020-01-01 00:00:00 UTC, 单位:毫秒.
+    pub const DEFAULT_EPOCH: i64 = 1577836800000;
+    /// 机器id的最大值.
+    pub const MAX_WORKER_ID: u64 = 0x3FF;
+
+    /// - @param worker_id 机器id, \[0,1023\].
+    /// - @exception 数值溢出, worker_id大于1023.
+    pub fn new(worker_id: u64) -> Result<Self, String> {
+        iceyee_error::ensure!(worker_id <= Self::MAX_WORKER_ID, "数值溢出", worker_id);
+        return Ok(Self {
+            worker_id: worker_id,
+            epoch: Self::DEFAULT_EPOCH,
+            state: Mutex::new((-1, 0)),
+        });
+    }
+
+    /// 起始时间, 单位:毫秒, 默认[Snowflake::DEFAULT_EPOCH], 可以使用约69年.
+    pub fn set_epoch(mut self, epoch: i64) -> Self {
+        self.epoch = epoch;
+        return self;
+    }
+
+    pub fn worker_id(&self) -> u64 {
+        return self.worker_id;
+    }
+
+    pub fn epoch(&self) -> i64 {
+        return self.epoch;
+    }
+
+    /// 下一个id.
+    pub fn next_id(&self) -> u64 {
+        let now: i64 = (iceyee_time::now() - self.epoch).max(0);
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let (last, sequence) = &mut *state;
+        if *last < now {
+            *last = now;
+            *sequence = 0;
+        } else if *sequence < 0xFFF {
+            *sequence += 1;
+        } else {
+            *last += 1;
+            *sequence = 0;
+        }
+        let timestamp: u64 = (*last as u64) & 0x1FF_FFFF_FFFF;
+        return (timestamp << 22) | (self.worker_id << 12) | *sequence;
+    }
+
+    /// 分解id, 返回(时间戳, 机器id, 序列号), 时间戳已经加上epoch, 单位:毫秒.
+    pub fn decompose(&self, id: u64) -> (i64, u64, u64) {
+        let timestamp: i64 = (id >> 22) as i64 + self.epoch;
+        return (timestamp, (id >> 12) & Self::MAX_WORKER_ID, id & 0xFFF);
+    }
+}
+
 /// 随机数.
 ///
-/// 种子是线程变量.
+/// 使用线程变量里的[Rng], 每个线程默认用[Rng::new]初始化.
 pub struct Random;
 
 impl Random {
-    /// 设置种子, 种子是线程变量.
+    /// 设置种子, 种子是线程变量, 相当于[Rng::from_seed].
     pub fn set_seed(s: u64) {
-        SEED.with(|seed| seed.set(s));
+        RNG.with(|rng| *rng.borrow_mut() = Rng::from_seed(s));
         return;
     }
 
     /// 下一个随机数.
     pub fn next() -> u64 {
-        let mut seed: u64 = SEED.with(|seed| {
-            if seed.get() == 0 {
-                let id: u64 = get_thread_id();
-                let time: u64 = SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_millis() as u64;
-                seed.set(id ^ time);
-            }
-            seed.get()
-        });
-        let thread_id: u64 = THREAD_ID.with(|id| {
-            if id.get() == 0 {
-                id.set(get_thread_id() & 0xFFFFFFFF)
-            }
-            id.get()
-        });
-        const TABLE: [u64; 32] = [
-            0x59763CEA1457DFC5,
-            0x0E701C6631DCCC01,
-            0x67793534A8C778D7,
-            0x64770DE4EB0B80CE,
-            0x747A9AF32BB93809,
-            0xC71D4F13A1E22A09,
-            0xE74813B3E6C1ECAB,
-            0x3B88A9B5A97C3862,
-            0x24057CC3128D9579,
-            0xD41D3C71A9426A59,
-            0xE5E61B92D1F676F4,
-            0xA01EC8F62398DE1C,
-            0x3025BB78C7E3DD78,
-            0xD869150399B67D2A,
-            0xD4F8F70CEEFBB738,
-            0xF910F138AFE1C1C9,
-            0xE63F12FA3125DB84,
-            0x84DC6ADA95196F95,
-            0x6C47E3124371EF67,
-            0x3339D137640D3929,
-            0x6604916E4DF3C5AF,
-            0x8D86EBD5FD2374CD,
-            0xAD13E8135162601F,
-            0xDA5C5215F3867431,
-            0x1540D632794D96C6,
-            0x012533A3629D7DE8,
-            0x238F9B1046DDCA4C,
-            0xB61FAC20EBE58CEA,
-            0xF6982B86164D089B,
-            0xEAA86C9587A038B3,
-            0xF4B1F470ABDA3652,
-            0x10A7C3C4A75EF01E,
-        ];
-        const N: usize = 2;
-        for x in 0..N {
-            seed = (seed << 63) | (seed >> 1);
-            seed ^= TABLE[x];
-            seed = ((seed as u128) + (TABLE[x + N] as u128)) as u64;
-        }
-        seed += thread_id;
-        SEED.with(|s| s.set(seed));
-        return seed;
-    }
-
-    /// 下一个小于max的随机数, 相当于next() % max.
+        return Self::with(|rng| rng.next_u64());
+    }
+
+    /// 均匀分布在\[0,max)的随机数, max为0时返回0, 见[Generator::next_less_than].
     pub fn next_less_than(max: u64) -> u64 {
-        return Self::next() % max;
+        return Self::with(|rng| rng.next_less_than(max));
+    }
+
+    /// 均匀分布在\[0,1)的浮点数, 见[Generator::next_f64].
+    pub fn next_f64() -> f64 {
+        return Self::with(|rng| rng.next_f64());
+    }
+
+    /// 用随机数填满output.
+    pub fn fill_bytes(output: &mut [u8]) {
+        Self::with(|rng| rng.fill_bytes(output));
+        return;
+    }
+
+    /// 见[Generator::shuffle].
+    pub fn shuffle<T>(slice: &mut [T]) {
+        Self::with(|rng| rng.shuffle(slice));
+        return;
+    }
+
+    /// 见[Generator::choose].
+    pub fn choose<T>(slice: &[T]) -> Option<&T> {
+        return Self::with(|rng| rng.choose(slice));
+    }
+
+    /// 见[Generator::choose_multiple].
+    pub fn choose_multiple<T>(slice: &[T], amount: usize) -> Vec<&T> {
+        return Self::with(|rng| rng.choose_multiple(slice, amount));
+    }
+
+    /// 访问线程变量里的[Rng], 不要在f里面再调用[Random]的函数.
+    pub fn with<F, T>(f: F) -> T
+    where
+        F: FnOnce(&mut Rng) -> T,
+    {
+        return RNG.with(|rng| f(&mut rng.borrow_mut()));
+    }
+}
+
+/// 密码学安全的随机数.
+///
+/// 使用线程变量里的[SecureRng], 每个线程第一次使用时从操作系统读取种子, 读取失败会panic.
+pub struct SecureRandom;
+
+impl SecureRandom {
+    /// 下一个随机数.
+    pub fn next() -> u64 {
+        return Self::with(|rng| rng.next_u64());
+    }
+
+    /// 用随机数填满output.
+    pub fn fill_bytes(output: &mut [u8]) {
+        Self::with(|rng| rng.fill_bytes(output));
+        return;
+    }
+
+    /// 见[SecureRng::token_bytes].
+    pub fn token_bytes(length: usize) -> Vec<u8> {
+        return Self::with(|rng| rng.token_bytes(length));
+    }
+
+    /// 见[SecureRng::token_hex].
+    pub fn token_hex(length: usize) -> String {
+        return Self::with(|rng| rng.token_hex(length));
+    }
+
+    /// 见[SecureRng::token_urlsafe].
+    pub fn token_urlsafe(length: usize) -> String {
+        return Self::with(|rng| rng.token_urlsafe(length));
+    }
+
+    /// 访问线程变量里的[SecureRng], 不要在f里面再调用[SecureRandom]的函数.
+    pub fn with<F, T>(f: F) -> T
+    where
+        F: FnOnce(&mut SecureRng) -> T,
+    {
+        return SECURE_RNG.with(|rng| f(&mut rng.borrow_mut()));
     }
 }
 
 /* Function. */
 
+/// 从操作系统读取随机数, unix是/dev/urandom, windows是RtlGenRandom.
+///
+/// - @exception 读取失败.
+pub fn fill_os_random(output: &mut [u8]) -> Result<(), String> {
+    #[cfg(unix)]
+    {
+        use std::io::Read;
+        let mut file: std::fs::File =
+            std::fs::File::open("/dev/urandom").map_err(|e| iceyee_error::c!(e))?;
+        file.read_exact(output).map_err(|e| iceyee_error::c!(e))?;
+        return Ok(());
+    }
+    #[cfg(windows)]
+    {
+        /* BOOLEAN RtlGenRandom(PVOID RandomBuffer, ULONG RandomBufferLength); */
+        #[link(name = "advapi32")]
+        unsafe extern "system" {
+            #[link_name = "SystemFunction036"]
+            fn RtlGenRandom(buffer: *mut u8, length: u32) -> u8;
+        }
+        for chunk in output.chunks_mut(u32::MAX as usize) {
+            if unsafe { RtlGenRandom(chunk.as_mut_ptr(), chunk.len() as u32) } == 0 {
+                return Err(iceyee_error::c!("RtlGenRandom"));
+            }
+        }
+        return Ok(());
+    }
+}
+
+/// 统计自检, FIPS 140-2的单比特, 扑克, 游程和长游程测试, 取20000比特.
+///
+/// 合格的生成器偶尔也会失败, 概率约万分之一.
+///
+/// - @exception 单比特测试失败.
+/// - @exception 扑克测试失败.
+/// - @exception 游程测试失败.
+/// - @exception 长游程测试失败.
+pub fn self_test<G>(generator: &mut G) -> Result<(), String>
+where
+    G: Generator,
+{
+    let mut sample: [u8; 2500] = [0; 2500];
+    generator.fill_bytes(&mut sample);
+    let ones: u32 = sample.iter().map(|x| x.count_ones()).sum();
+    iceyee_error::ensure!(9725 < ones && ones < 10275, "单比特测试失败", ones);
+    let mut counter: [u64; 16] = [0; 16];
+    for x in sample.iter() {
+        counter[(x >> 4) as usize] += 1;
+        counter[(x & 0xF) as usize] += 1;
+    }
+    let poker: f64 = 16.0 / 5000.0 * counter.iter().map(|x| (x * x) as f64).sum::<f64>() - 5000.0;
+    iceyee_error::ensure!(2.16 < poker && poker < 46.17, "扑克测试失败", poker);
+    const BOUNDS: [(u64, u64); 6] = [
+        (2315, 2685),
+        (1114, 1386),
+        (527, 723),
+        (240, 384),
+        (103, 209),
+        (103, 209),
+    ];
+    let mut runs: [[u64; 6]; 2] = [[0; 6]; 2];
+    let mut bit: usize = 2;
+    let mut length: usize = 0;
+    let mut longest: usize = 0;
+    for index in 0..=20000 {
+        let current: usize = if index == 20000 {
+            2
+        } else {
+            ((sample[index / 8] >> (7 - index % 8)) & 1) as usize
+        };
+        if current == bit {
+            length += 1;
+            continue;
+        }
+        if bit != 2 {
+            runs[bit][length.min(6) - 1] += 1;
+            longest = longest.max(length);
+        }
+        bit = current;
+        length = 1;
+    }
+    for (x, run) in runs.iter().enumerate() {
+        for (y, (count, (low, high))) in run.iter().zip(BOUNDS).enumerate() {
+            iceyee_error::ensure!(
+                low <= *count && *count <= high,
+                "游程测试失败",
+                x,
+                y + 1,
+                count
+            );
+        }
+    }
+    iceyee_error::ensure!(longest < 26, "长游程测试失败", longest);
+    return Ok(());
+}
+
+/* ln(k!), k是非负整数, 小于10查表, 否则用Stirling级数. */
+fn log_factorial(k: f64) -> f64 {
+    const TABLE: [f64; 10] = [
+        0.0,
+        0.0,
+        std::f64::consts::LN_2,
+        1.791759469228055,
+        3.1780538303479458,
+        4.787491742782046,
+        6.579251212010101,
+        8.525161361065415,
+        10.60460290274525,
+        12.801827480081469,
+    ];
+    if k < 10.0 {
+        return TABLE[k as usize];
+    }
+    let x: f64 = k + 1.0;
+    let x2: f64 = x * x;
+    return (x - 0.5) * x.ln() - x
+        + 0.5 * (2.0 * std::f64::consts::PI).ln()
+        + (1.0 / 12.0 - (1.0 / 360.0 - 1.0 / (1260.0 * x2)) / x2) / x;
+}
+
+fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+    return;
+}
+
+fn splitmix64(x: &mut u64) -> u64 {
+    *x = x.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z: u64 = *x;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    return z ^ (z >> 31);
+}
+
 /* 取线程id. */
 fn get_thread_id() -> u64 {
     #[cfg(target_os = "linux")]
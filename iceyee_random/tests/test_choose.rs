@@ -0,0 +1,153 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_random::AliasTable;
+use iceyee_random::Generator;
+use iceyee_random::Random;
+use iceyee_random::Rng;
+use iceyee_random::SecureRng;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_shuffle() {
+    println!("");
+    println!("打乱后还是原来的元素, 每个位置上各个元素出现的次数相近.");
+    let mut rng: Rng = Rng::from_seed(0xFFFF);
+    let mut counter: [[u64; 4]; 4] = [[0; 4]; 4];
+    for _ in 0..40000 {
+        let mut a: [usize; 4] = [0, 1, 2, 3];
+        rng.shuffle(&mut a);
+        let mut b: [usize; 4] = a;
+        b.sort();
+        assert_eq!(b, [0, 1, 2, 3]);
+        for (position, value) in a.iter().enumerate() {
+            counter[position][*value] += 1;
+        }
+    }
+    println!("{:?}", counter);
+    assert!(counter.iter().flatten().all(|x| 9500 < *x && *x < 10500));
+    let mut empty: [u8; 0] = [];
+    rng.shuffle(&mut empty);
+    println!("相同的种子得到相同的结果.");
+    let mut a: Vec<u64> = (0..100).collect();
+    let mut b: Vec<u64> = (0..100).collect();
+    Rng::from_seed(1).shuffle(&mut a);
+    Rng::from_seed(1).shuffle(&mut b);
+    assert_eq!(a, b);
+    Random::set_seed(1);
+    let mut c: Vec<u64> = (0..100).collect();
+    Random::shuffle(&mut c);
+    assert_eq!(a, c);
+    return;
+}
+
+#[test]
+pub fn test_choose() {
+    println!("");
+    let mut rng: Rng = Rng::from_seed(0xFFFF);
+    let empty: [u8; 0] = [];
+    assert_eq!(rng.choose(&empty), None);
+    assert_eq!(Random::choose(&empty), None);
+    assert_eq!(rng.choose(&[7]), Some(&7));
+    let a: [u64; 5] = [0, 1, 2, 3, 4];
+    let mut counter: [u64; 5] = [0; 5];
+    for _ in 0..50000 {
+        counter[*rng.choose(&a).expect("NEVER") as usize] += 1;
+    }
+    println!("{:?}", counter);
+    assert!(counter.iter().all(|x| 9500 < *x && *x < 10500));
+    println!("不重复地取多个.");
+    for _ in 0..1000 {
+        let mut b: Vec<&u64> = rng.choose_multiple(&a, 3);
+        assert_eq!(b.len(), 3);
+        b.sort();
+        b.dedup();
+        assert_eq!(b.len(), 3);
+    }
+    let mut b: Vec<&u64> = Random::choose_multiple(&a, 10);
+    b.sort();
+    assert_eq!(b, a.iter().collect::<Vec<&u64>>());
+    assert!(rng.choose_multiple(&empty, 3).is_empty());
+    assert!(rng.choose_multiple(&a, 0).is_empty());
+    return;
+}
+
+#[test]
+pub fn test_reservoir_sample() {
+    println!("");
+    let mut rng: SecureRng = SecureRng::new();
+    println!("不够则全部取出.");
+    assert_eq!(rng.reservoir_sample(0..3, 5), vec![0, 1, 2]);
+    assert!(rng.reservoir_sample(0..3, 0).is_empty());
+    println!("每个元素被选中的概率都是amount/n.");
+    let mut rng: Rng = Rng::from_seed(0xFFFF);
+    let mut counter: [u64; 10] = [0; 10];
+    for _ in 0..10000 {
+        let sample: Vec<usize> = rng.reservoir_sample((0..10).filter(|_| true), 3);
+        assert_eq!(sample.len(), 3);
+        for x in sample {
+            counter[x] += 1;
+        }
+    }
+    println!("{:?}", counter);
+    assert!(counter.iter().all(|x| 2800 < *x && *x < 3200));
+    return;
+}
+
+#[test]
+pub fn test_alias_table() {
+    println!("");
+    println!("异常输入.");
+    assert_eq!(
+        AliasTable::new(&[]).map_err(|x| x.contains("权重为空")),
+        Err(true)
+    );
+    for weights in [
+        &[0.0, 0.0][..],
+        &[1.0, -1.0],
+        &[1.0, f64::NAN],
+        &[f64::INFINITY],
+        &[f64::MAX, f64::MAX],
+    ] {
+        assert_eq!(
+            AliasTable::new(weights).map_err(|x| x.contains("无效的权重")),
+            Err(true)
+        );
+    }
+    println!("按权重抽样, 权重为0的不会被选中.");
+    let weights: [f64; 5] = [5.0, 0.0, 3.0, 1.5, 0.5];
+    let table: AliasTable = AliasTable::new(&weights).expect("NEVER");
+    assert_eq!(table.len(), 5);
+    assert!(!table.is_empty());
+    let mut rng: Rng = Rng::from_seed(0xFFFF);
+    let mut counter: [u64; 5] = [0; 5];
+    for _ in 0..100000 {
+        counter[table.sample(&mut rng)] += 1;
+    }
+    println!("{:?}", counter);
+    assert_eq!(counter[1], 0);
+    for x in 0..5 {
+        let expected: f64 = weights[x] * 10000.0;
+        assert!((counter[x] as f64 - expected).abs() <= expected * 0.05);
+    }
+    println!("线程变量, 以及取元素.");
+    let proxies: [&str; 5] = ["a", "b", "c", "d", "e"];
+    let proxy: &str = Random::with(|rng| *table.choose(rng, &proxies).expect("NEVER"));
+    assert_ne!(proxy, "b");
+    assert_eq!(table.choose(&mut rng, &proxies[..4]), None);
+    let table: AliasTable = AliasTable::new(&[0.0, 2.0]).expect("NEVER");
+    assert!((0..100).all(|_| table.sample(&mut rng) == 1));
+    return;
+}
@@ -0,0 +1,135 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_random::Generator;
+use iceyee_random::Random;
+use iceyee_random::Rng;
+use iceyee_random::SecureRng;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+/// 样本的均值和方差.
+fn mean_variance(samples: &[f64]) -> (f64, f64) {
+    let n: f64 = samples.len() as f64;
+    let mean: f64 = samples.iter().sum::<f64>() / n;
+    let variance: f64 = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+    return (mean, variance);
+}
+
+#[test]
+pub fn test_range() {
+    println!("");
+    let mut rng: Rng = Rng::from_seed(0xFFFF);
+    println!("max为0不会panic.");
+    assert_eq!(rng.next_less_than(0), 0);
+    assert_eq!(Random::next_less_than(0), 0);
+    assert_eq!(rng.next_less_than(1), 0);
+    println!("区间边界.");
+    assert_eq!(rng.range_u64(5, 5), 5);
+    assert_eq!(rng.range_u64(5, 3), 5);
+    assert_eq!(rng.range_inclusive_u64(5, 5), 5);
+    assert_eq!(rng.range_i64(-3, -3), -3);
+    assert_eq!(rng.range_inclusive_i64(7, 7), 7);
+    rng.range_inclusive_u64(0, u64::MAX);
+    rng.range_inclusive_i64(i64::MIN, i64::MAX);
+    let mut counter: [u64; 7] = [0; 7];
+    for _ in 0..70000 {
+        let x: i64 = rng.range_inclusive_i64(-3, 3);
+        assert!(-3 <= x && x <= 3);
+        counter[(x + 3) as usize] += 1;
+        let x: i64 = rng.range_i64(i64::MIN, i64::MIN + 2);
+        assert!(x == i64::MIN || x == i64::MIN + 1);
+        let x: u64 = rng.range_u64(u64::MAX - 1, u64::MAX);
+        assert_eq!(x, u64::MAX - 1);
+    }
+    println!("{:?}", counter);
+    for x in counter {
+        assert!(9500 < x && x < 10500);
+    }
+    println!("没有偏差, max=3*2^62时, 取模会让[0,2^62)的概率是1/2, 而不是1/3.");
+    let max: u64 = 3 << 62;
+    let mut low: u64 = 0;
+    for _ in 0..10000 {
+        if rng.next_less_than(max) < (1 << 62) {
+            low += 1;
+        }
+    }
+    println!("{}", low);
+    assert!(3100 < low && low < 3566);
+    return;
+}
+
+#[test]
+pub fn test_float() {
+    println!("");
+    let mut rng: Rng = Rng::from_seed(0xFFFF);
+    let mut samples: Vec<f64> = Vec::new();
+    for _ in 0..100000 {
+        let x: f64 = rng.next_f64();
+        assert!(0.0 <= x && x < 1.0);
+        samples.push(x);
+        let x: f32 = rng.next_f32();
+        assert!(0.0 <= x && x < 1.0);
+    }
+    let (mean, variance) = mean_variance(&samples);
+    println!("均匀分布, 均值{}, 方差{}.", mean, variance);
+    assert!((mean - 0.5).abs() < 0.01);
+    assert!((variance - 1.0 / 12.0).abs() < 0.01);
+    let x: f64 = Random::next_f64();
+    assert!(0.0 <= x && x < 1.0);
+    return;
+}
+
+#[test]
+pub fn test_distribution() {
+    println!("");
+    let mut rng: Rng = Rng::from_seed(0xFFFF);
+    const N: usize = 100000;
+    println!("伯努利分布.");
+    let count: usize = (0..N).filter(|_| rng.bernoulli(0.3)).count();
+    println!("{}", count);
+    assert!(29000 < count && count < 31000);
+    assert!((0..100).all(|_| !rng.bernoulli(0.0) && rng.bernoulli(1.0)));
+    println!("正态分布.");
+    let samples: Vec<f64> = (0..N).map(|_| rng.normal(5.0, 2.0)).collect();
+    let (mean, variance) = mean_variance(&samples);
+    println!("均值{}, 方差{}.", mean, variance);
+    assert!((mean - 5.0).abs() < 0.05);
+    assert!((variance - 4.0).abs() < 0.1);
+    println!("指数分布.");
+    let samples: Vec<f64> = (0..N).map(|_| rng.exponential(0.5)).collect();
+    let (mean, variance) = mean_variance(&samples);
+    println!("均值{}, 方差{}.", mean, variance);
+    assert!(samples.iter().all(|x| 0.0 <= *x && x.is_finite()));
+    assert!((mean - 2.0).abs() < 0.05);
+    assert!((variance - 4.0).abs() < 0.2);
+    println!("泊松分布.");
+    for lambda in [0.5, 3.0, 10.0, 45.5, 1000.0] {
+        let samples: Vec<f64> = (0..N).map(|_| rng.poisson(lambda) as f64).collect();
+        let (mean, variance) = mean_variance(&samples);
+        println!("lambda={}, 均值{}, 方差{}.", lambda, mean, variance);
+        assert!((mean - lambda).abs() < lambda.sqrt() * 0.02);
+        assert!((variance - lambda).abs() < lambda * 0.03);
+    }
+    assert_eq!(rng.poisson(0.0), 0);
+    assert_eq!(rng.poisson(-1.0), 0);
+    assert_eq!(rng.poisson(f64::NAN), 0);
+    println!("SecureRng和线程变量同样可用.");
+    let mut rng: SecureRng = SecureRng::new();
+    let x: f64 = rng.normal(0.0, 1.0);
+    assert!(x.is_finite());
+    let x: u64 = Random::with(|rng| rng.range_u64(10, 20));
+    assert!(10 <= x && x < 20);
+    return;
+}
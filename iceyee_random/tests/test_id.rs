@@ -0,0 +1,173 @@
+// **************************************************
+// *  Author: Iceyee                                *
+// *  Mail: iceyee.studio@qq.com                    *
+// *  Git: https://github.com/iceyee                *
+// **************************************************
+//
+// Use.
+
+use iceyee_random::Rng;
+use iceyee_random::Snowflake;
+use iceyee_random::Ulid;
+use iceyee_random::Uuid;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+// Enum.
+
+// Trait.
+
+// Struct.
+
+// Function.
+
+#[test]
+pub fn test_uuid() {
+    println!("");
+    println!("v4.");
+    let a: Uuid = Uuid::new_v4();
+    println!("{}", a);
+    assert_eq!(a.version(), 4);
+    assert_eq!(a.timestamp(), None);
+    assert_eq!(a.to_string().len(), 36);
+    assert_eq!((a.as_u128() >> 62) & 0x3, 0x2);
+    assert_ne!(a, Uuid::new_v4());
+    assert_eq!(
+        Uuid::new_v4_with(&mut Rng::from_seed(1)),
+        Uuid::new_v4_with(&mut Rng::from_seed(1))
+    );
+    println!("v7, 严格递增.");
+    let before: i64 = iceyee_time::now();
+    let ids: Vec<Uuid> = (0..10000).map(|_| Uuid::new_v7()).collect();
+    let after: i64 = iceyee_time::now();
+    println!("{}", ids[0]);
+    for x in 1..ids.len() {
+        assert!(ids[x - 1] < ids[x]);
+        assert!(ids[x - 1].to_string() < ids[x].to_string());
+    }
+    assert_eq!(ids[0].version(), 7);
+    assert!(before <= ids[0].timestamp().expect("NEVER"));
+    assert!(ids[9999].timestamp().expect("NEVER") <= after + 3);
+    println!("格式化和解析, RFC 9562的例子.");
+    let a: Uuid = "017F22E2-79B0-7CC3-98C4-DC0C0C07398F"
+        .parse()
+        .expect("NEVER");
+    assert_eq!(a.to_string(), "017f22e2-79b0-7cc3-98c4-dc0c0c07398f");
+    assert_eq!(a.version(), 7);
+    assert_eq!(a.timestamp(), Some(1645557742000));
+    assert_eq!("017f22e279b07cc398c4dc0c0c07398f".parse::<Uuid>(), Ok(a));
+    assert_eq!(Uuid::from_bytes(a.to_bytes()), a);
+    assert_eq!(Uuid::from_u128(a.as_u128()), a);
+    assert_eq!(
+        Uuid::nil().to_string(),
+        "00000000-0000-0000-0000-000000000000"
+    );
+    for s in [
+        "",
+        "017f22e2-79b0-7cc3-98c4-dc0c0c07398",
+        "017f22e2_79b0-7cc3-98c4-dc0c0c07398f",
+        "017f22e2-79b0-7cc3-98c4-dc0c0c07398g",
+        "+17f22e279b07cc398c4dc0c0c07398f",
+        "00000000-0000-0000-0000-0000000000-0",
+    ] {
+        assert_eq!(
+            s.parse::<Uuid>().map_err(|x| x.contains("错误的格式")),
+            Err(true)
+        );
+    }
+    return;
+}
+
+#[test]
+pub fn test_ulid() {
+    println!("");
+    println!("格式化和解析, 规范里的例子.");
+    let a: Ulid = "01ARZ3NDEKTSV4RRFFQ69G5FAV".parse().expect("NEVER");
+    assert_eq!(a.timestamp(), 1469922850259);
+    assert_eq!(a.random(), 0xD6764C61EFB99302BD5B);
+    assert_eq!(Ulid::from_parts(1469922850259, 0xD6764C61EFB99302BD5B), a);
+    assert_eq!(a.to_string(), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+    assert_eq!("01arz3ndektsv4rrffq69g5fav".parse::<Ulid>(), Ok(a));
+    assert_eq!(
+        "0LARZ3NDEKTSV4RRFFQ69G5FAV".parse::<Ulid>(),
+        "01ARZ3NDEKTSV4RRFFQ69G5FAV".parse::<Ulid>()
+    );
+    assert_eq!(Ulid::from_bytes(a.to_bytes()), a);
+    assert_eq!(
+        "7ZZZZZZZZZZZZZZZZZZZZZZZZZ".parse::<Ulid>(),
+        Ok(Ulid::from_u128(u128::MAX))
+    );
+    assert_eq!(
+        "8ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+            .parse::<Ulid>()
+            .map_err(|x| x.contains("数值溢出")),
+        Err(true)
+    );
+    for s in [
+        "",
+        "01ARZ3NDEKTSV4RRFFQ69G5FA",
+        "01ARZ3NDEKTSV4RRFFQ69G5FAU",
+    ] {
+        assert_eq!(
+            s.parse::<Ulid>().map_err(|x| x.contains("错误的格式")),
+            Err(true)
+        );
+    }
+    println!("严格递增.");
+    let before: i64 = iceyee_time::now();
+    let ids: Vec<Ulid> = (0..10000).map(|_| Ulid::new()).collect();
+    println!("{}", ids[0]);
+    for x in 1..ids.len() {
+        assert!(ids[x - 1] < ids[x]);
+        assert!(ids[x - 1].to_string() < ids[x].to_string());
+    }
+    assert!(before <= ids[0].timestamp());
+    return;
+}
+
+#[test]
+pub fn test_snowflake() {
+    println!("");
+    assert_eq!(
+        Snowflake::new(1024)
+            .map(|_| ())
+            .map_err(|x| x.contains("数值溢出")),
+        Err(true)
+    );
+    let snowflake: Snowflake = Snowflake::new(Snowflake::MAX_WORKER_ID).expect("NEVER");
+    assert_eq!(snowflake.epoch(), Snowflake::DEFAULT_EPOCH);
+    println!("分解.");
+    let before: i64 = iceyee_time::now();
+    let id: u64 = snowflake.next_id();
+    println!("{}", id);
+    let (timestamp, worker_id, sequence) = snowflake.decompose(id);
+    assert!(before <= timestamp && timestamp <= iceyee_time::now());
+    assert_eq!(worker_id, 1023);
+    assert!(sequence <= 0xFFF);
+    assert!(id < 1 << 63);
+    println!("多线程, 严格递增, 不重复.");
+    let snowflake: Arc<Snowflake> = Arc::new(
+        Snowflake::new(7)
+            .expect("NEVER")
+            .set_epoch(iceyee_time::now() - 1000),
+    );
+    let handles: Vec<std::thread::JoinHandle<Vec<u64>>> = (0..4)
+        .map(|_| {
+            let snowflake: Arc<Snowflake> = snowflake.clone();
+            std::thread::spawn(move || (0..10000).map(|_| snowflake.next_id()).collect())
+        })
+        .collect();
+    let mut all: HashSet<u64> = HashSet::new();
+    for handle in handles {
+        let ids: Vec<u64> = handle.join().expect("NEVER");
+        for x in 1..ids.len() {
+            assert!(ids[x - 1] < ids[x]);
+        }
+        for id in ids {
+            assert_eq!(snowflake.decompose(id).1, 7);
+            assert!(all.insert(id));
+        }
+    }
+    assert_eq!(all.len(), 40000);
+    return;
+}
@@ -6,7 +6,12 @@
 //
 // Use.
 
+use iceyee_encoder::HexEncoder;
+use iceyee_random::Generator;
 use iceyee_random::Random;
+use iceyee_random::Rng;
+use iceyee_random::SecureRandom;
+use iceyee_random::SecureRng;
 
 // Enum.
 
@@ -42,3 +47,130 @@ pub fn test_random() {
     }
     return;
 }
+
+#[test]
+pub fn test_rng() {
+    println!("");
+    println!("xoshiro256**, splitmix64(0)扩展的种子.");
+    let mut rng: Rng = Rng::from_seed(0);
+    assert_eq!(rng.next_u64(), 0x99EC5F36CB75F2B4);
+    assert_eq!(rng.next_u64(), 0xBF6E1F784956452A);
+    assert_eq!(rng.next_u64(), 0x1A5F849D4933E6E0);
+    println!("相同的种子, 在不同的线程得到相同的序列.");
+    let expected: Vec<u64> = {
+        let mut rng: Rng = Rng::from_seed(0xFFFF);
+        (0..100).map(|_| rng.next_u64()).collect()
+    };
+    let handles: Vec<std::thread::JoinHandle<Vec<u64>>> = (0..4)
+        .map(|_| {
+            std::thread::spawn(|| {
+                let mut rng: Rng = Rng::from_seed(0xFFFF);
+                (0..100).map(|_| rng.next_u64()).collect()
+            })
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().expect("NEVER"), expected);
+    }
+    let handle = std::thread::spawn(|| {
+        Random::set_seed(0xFFFF);
+        (0..100).map(|_| Random::next()).collect::<Vec<u64>>()
+    });
+    assert_eq!(handle.join().expect("NEVER"), expected);
+    println!("Clone, 以及保存和恢复状态.");
+    let mut a: Rng = Rng::from_seed(1);
+    a.next_u64();
+    let mut b: Rng = a.clone();
+    let mut c: Rng = Rng::from_state(&a.state()).expect("NEVER");
+    for _ in 0..100 {
+        let x: u64 = a.next_u64();
+        assert_eq!(b.next_u64(), x);
+        assert_eq!(c.next_u64(), x);
+    }
+    assert!(Rng::from_state(&[0; 32]).is_none());
+    println!("serde.");
+    let json: String = serde_json::to_string(&a).expect("NEVER");
+    println!("{}", json);
+    let mut d: Rng = serde_json::from_str(&json).expect("NEVER");
+    assert_eq!(d, a);
+    assert_eq!(d.next_u64(), a.next_u64());
+    assert!(serde_json::from_str::<Rng>("[0,0,0,0]").is_err());
+    assert!(serde_json::from_str::<Rng>("[1,2,3]").is_err());
+    println!("字节种子.");
+    assert_eq!(Rng::from_bytes(b"iceyee"), Rng::from_bytes(b"iceyee"));
+    assert_ne!(Rng::from_bytes(b"iceyee"), Rng::from_bytes(b"iceyee\0"));
+    assert_ne!(Rng::from_bytes(b""), Rng::from_bytes(&[0]));
+    assert_ne!(Rng::new(), Rng::new());
+    let mut buffer: [u8; 11] = [0; 11];
+    Rng::from_seed(0).fill_bytes(&mut buffer);
+    assert_eq!(buffer[..8], 0x99EC5F36CB75F2B4_u64.to_le_bytes());
+    assert_eq!(buffer[8..], 0xBF6E1F784956452A_u64.to_le_bytes()[..3]);
+    return;
+}
+
+#[test]
+pub fn test_secure_rng() {
+    println!("");
+    println!("ChaCha20, RFC 8439 A.1, 密钥和nonce都是0.");
+    let mut rng: SecureRng = SecureRng::from_seed(&[0; 32]);
+    let mut buffer: [u8; 80] = [0; 80];
+    rng.fill_bytes(&mut buffer[..5]);
+    rng.fill_bytes(&mut buffer[5..70]);
+    rng.fill_bytes(&mut buffer[70..]);
+    assert_eq!(
+        HexEncoder::encode(&buffer).to_ascii_lowercase(),
+        "76b8e0ada0f13d90405d6ae55386bd28bdd219b8a08ded1aa836efcc8b770dc7\
+         da41597c5157488d7724e03fb8d84a376a43b8f41518a11cc387b669b2ee6586\
+         9f07e7be5551387a98ba977c732d080d"
+    );
+    let mut rng: SecureRng = SecureRng::from_seed(&[0; 32]);
+    assert_eq!(rng.next_u64(), 0x903DF1A0ADE0B876);
+    assert_eq!(rng.next_u64(), 0x28BD8653E56A5D40);
+    println!("令牌.");
+    let token: String = SecureRandom::token_hex(16);
+    println!("{}", token);
+    assert_eq!(token.len(), 32);
+    assert!(
+        token
+            .chars()
+            .all(|x| x.is_ascii_hexdigit() && !x.is_ascii_uppercase())
+    );
+    assert_ne!(token, SecureRandom::token_hex(16));
+    let token: String = SecureRandom::token_urlsafe(32);
+    println!("{}", token);
+    assert_eq!(token.len(), 43);
+    assert!(
+        token
+            .chars()
+            .all(|x| x.is_ascii_alphanumeric() || x == '-' || x == '_')
+    );
+    assert_eq!(SecureRandom::token_bytes(7).len(), 7);
+    let mut a: SecureRng = SecureRng::new();
+    let mut b: SecureRng = SecureRng::new();
+    assert_ne!(a.next_u64(), b.next_u64());
+    println!("自检.");
+    iceyee_random::self_test(&mut Rng::from_seed(0xFFFF)).expect("NEVER");
+    iceyee_random::self_test(&mut SecureRng::from_seed(&[0; 32])).expect("NEVER");
+    struct Counter(u64);
+    impl Generator for Counter {
+        fn next_u64(&mut self) -> u64 {
+            self.0 += 1;
+            return self.0;
+        }
+    }
+    assert_eq!(
+        iceyee_random::self_test(&mut Counter(0)).map_err(|x| x.contains("单比特测试失败")),
+        Err(true)
+    );
+    struct Alternate;
+    impl Generator for Alternate {
+        fn next_u64(&mut self) -> u64 {
+            return 0x5555555555555555;
+        }
+    }
+    assert_eq!(
+        iceyee_random::self_test(&mut Alternate).map_err(|x| x.contains("扑克测试失败")),
+        Err(true)
+    );
+    return;
+}